@@ -0,0 +1,12 @@
+use tdameritrade_rust::{init, FileTokenStore, TDAClientError, TokenStore};
+mod config;
+
+fn main() -> Result<(), TDAClientError> {
+    // Create Token Without Chromedriver. Redirect URI Must Be An http URL On This Machine
+    let token = init::create_token_with_listener(config::client_id(), config::redirect_uri())?;
+
+    // Write Token File
+    FileTokenStore::new(config::token_path()).save(&token)?;
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use tdameritrade_rust::{
+    output::quotes::{QuoteType, Quotes},
+    AsyncMarketDataClient, TDAClientError,
+};
+mod config;
+
+#[tokio::main]
+async fn main() -> Result<(), TDAClientError> {
+    // Create Asynchronous Market Data Client. No Token Needed
+    let client = AsyncMarketDataClient::new(config::client_id())?;
+
+    // Get Delayed Quote
+    let symbol = "AAPL";
+    let res_json: Quotes = client.get_quote_typed(symbol).await?;
+
+    if let QuoteType::Equity(equity) = &res_json.symbol[symbol] {
+        println!("{}", equity.close_price);
+    }
+
+    Ok(())
+}
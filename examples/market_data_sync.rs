@@ -0,0 +1,20 @@
+use tdameritrade_rust::{
+    output::quotes::{QuoteType, Quotes},
+    SyncMarketDataClient, TDAClientError,
+};
+mod config;
+
+fn main() -> Result<(), TDAClientError> {
+    // Create Synchronous Market Data Client. No Token Needed
+    let client = SyncMarketDataClient::new(config::client_id())?;
+
+    // Get Delayed Quote
+    let symbol = "AAPL";
+    let res_json: Quotes = client.get_quote_typed(symbol)?;
+
+    if let QuoteType::Equity(equity) = &res_json.symbol[symbol] {
+        println!("{}", equity.close_price);
+    }
+
+    Ok(())
+}
@@ -4,11 +4,12 @@ mod config;
 #[tokio::main]
 async fn main() -> Result<(), TDAClientError> {
     // Create Asynchronous TDAClient
-    let client = AsyncTDAClient::new(
+    let client = AsyncTDAClient::new_async(
         config::client_id(),
         config::redirect_uri(),
         config::token_path(),
-    )?;
+    )
+    .await?;
 
     // Get Hours For Multiple Markets
     let markets = vec!["EQUITY", "OPTION", "FUTURE", "BOND", "FOREX"];
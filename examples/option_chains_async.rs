@@ -6,11 +6,12 @@ mod config;
 #[tokio::main]
 async fn main() -> Result<(), TDAClientError> {
     // Create Asynchronous TDAClient
-    let client = AsyncTDAClient::new(
+    let client = AsyncTDAClient::new_async(
         config::client_id(),
         config::redirect_uri(),
         config::token_path(),
-    )?;
+    )
+    .await?;
 
     // Get Option Chain
     let option_params = OptionChainParams::default()
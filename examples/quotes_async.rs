@@ -7,16 +7,16 @@ mod config;
 #[tokio::main]
 async fn main() -> Result<(), TDAClientError> {
     // Create Asynchronous TDAClient
-    let client = AsyncTDAClient::new(
+    let client = AsyncTDAClient::new_async(
         config::client_id(),
         config::redirect_uri(),
         config::token_path(),
-    )?;
+    )
+    .await?;
 
     // Get Quote
     let symbol = "AAPL";
-    let res = client.get_quote(symbol).await?;
-    let res_json = serde_json::from_str::<Quotes>(&res)?;
+    let res_json: Quotes = client.get_quote_typed(symbol).await?;
 
     if let QuoteType::Equity(equity) = &res_json.symbol[symbol] {
         println!("{}", equity.close_price);
@@ -24,8 +24,7 @@ async fn main() -> Result<(), TDAClientError> {
 
     // Get Quotes
     let symbols = vec!["AAPL", "AMZN", "AMD", "NVDA"];
-    let res = client.get_quotes(&symbols).await?;
-    let res_json = serde_json::from_str::<Quotes>(&res)?;
+    let res_json: Quotes = client.get_quotes_typed(&symbols).await?;
 
     for symbol in symbols.into_iter() {
         if let QuoteType::Equity(equity) = &res_json.symbol[symbol] {
@@ -14,8 +14,7 @@ fn main() -> Result<(), TDAClientError> {
 
     // Get Quote
     let symbol = "AAPL";
-    let res = client.get_quote(symbol)?;
-    let res_json = serde_json::from_str::<Quotes>(&res)?;
+    let res_json: Quotes = client.get_quote_typed(symbol)?;
 
     if let QuoteType::Equity(equity) = &res_json.symbol[symbol] {
         println!("{}", equity.close_price);
@@ -23,8 +22,7 @@ fn main() -> Result<(), TDAClientError> {
 
     // Get Quotes
     let symbols = vec!["AAPL", "AMZN", "AMD", "NVDA"];
-    let res = client.get_quotes(&symbols)?;
-    let res_json = serde_json::from_str::<Quotes>(&res)?;
+    let res_json: Quotes = client.get_quotes_typed(&symbols)?;
 
     for symbol in symbols.into_iter() {
         if let QuoteType::Equity(equity) = &res_json.symbol[symbol] {
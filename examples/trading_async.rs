@@ -6,11 +6,12 @@ mod config;
 #[tokio::main]
 async fn main() -> Result<(), TDAClientError> {
     // Create Asynchronous TDAClient
-    let client = AsyncTDAClient::new(
+    let client = AsyncTDAClient::new_async(
         config::client_id(),
         config::redirect_uri(),
         config::token_path(),
-    )?;
+    )
+    .await?;
 
     // Get Account Id
     let acct_id = config::acct_id();
@@ -55,11 +56,13 @@ async fn main() -> Result<(), TDAClientError> {
     }
 
     // Place Order
-    place_order(&client, acct_id).await?; // Limit Order For 1 Share Of AAPL At $1000
+    let new_order_id = place_order(&client, acct_id).await?; // Limit Order For 1 Share Of AAPL At $1000
+    println!("{:?}", new_order_id);
 
     // Replace Order
     let replace_order_id = 0;
-    replace_order(&client, acct_id, replace_order_id).await?; // Replace Existing Order For 1 Share Of AAPL At $1000
+    let new_order_id = replace_order(&client, acct_id, replace_order_id).await?; // Replace Existing Order For 1 Share Of AAPL At $1000
+    println!("{:?}", new_order_id);
 
     // Cancel Order
     let cancel_order_id = 0;
@@ -85,7 +88,8 @@ async fn main() -> Result<(), TDAClientError> {
     }
 
     // Create Saved Order
-    create_saved_order(&client, acct_id).await?; // Create Saved Limit Order For 1 Share Of AAPL At $1000
+    let saved_order_id = create_saved_order(&client, acct_id).await?; // Create Saved Limit Order For 1 Share Of AAPL At $1000
+    println!("{:?}", saved_order_id);
 
     // Replace Saved Order
     let replace_order_id = 0;
@@ -98,7 +102,7 @@ async fn main() -> Result<(), TDAClientError> {
     Ok(())
 }
 
-async fn place_order(client: &AsyncTDAClient, acct_id: i64) -> Result<(), TDAClientError> {
+async fn place_order(client: &AsyncTDAClient, acct_id: i64) -> Result<Option<i64>, TDAClientError> {
     let order_spec = equity_buy_limit("AAPL", 1.0, 1000.0).build()?;
 
     /*
@@ -129,7 +133,7 @@ async fn replace_order(
     client: &AsyncTDAClient,
     acct_id: i64,
     order_id: i64,
-) -> Result<(), TDAClientError> {
+) -> Result<Option<i64>, TDAClientError> {
     let order_spec = r#"{
         "orderType": "LIMIT",
         "session": "SEAMLESS",
@@ -151,7 +155,10 @@ async fn replace_order(
     client.replace_order(acct_id, order_id, order_spec).await
 }
 
-async fn create_saved_order(client: &AsyncTDAClient, acct_id: i64) -> Result<(), TDAClientError> {
+async fn create_saved_order(
+    client: &AsyncTDAClient,
+    acct_id: i64,
+) -> Result<Option<i64>, TDAClientError> {
     let order_spec = r#"{
         "orderType": "LIMIT",
         "session": "SEAMLESS",
@@ -50,11 +50,13 @@ fn main() -> Result<(), TDAClientError> {
     }
 
     // Place Order
-    place_order(&client, acct_id)?; // Limit Order For 1 Share Of AAPL At $1000
+    let new_order_id = place_order(&client, acct_id)?; // Limit Order For 1 Share Of AAPL At $1000
+    println!("{:?}", new_order_id);
 
     // Replace Order
     let replace_order_id = 0;
-    replace_order(&client, acct_id, replace_order_id)?; // Replace Existing Order For 1 Share Of AAPL At $1000
+    let new_order_id = replace_order(&client, acct_id, replace_order_id)?; // Replace Existing Order For 1 Share Of AAPL At $1000
+    println!("{:?}", new_order_id);
 
     // Cancel Order
     let cancel_order_id = 0;
@@ -80,7 +82,8 @@ fn main() -> Result<(), TDAClientError> {
     }
 
     // Create Saved Order
-    create_saved_order(&client, acct_id)?; // Create Saved Limit Order For 1 Share Of AAPL At $1000
+    let saved_order_id = create_saved_order(&client, acct_id)?; // Create Saved Limit Order For 1 Share Of AAPL At $1000
+    println!("{:?}", saved_order_id);
 
     // Replace Saved Order
     let replace_order_id = 0;
@@ -93,7 +96,7 @@ fn main() -> Result<(), TDAClientError> {
     Ok(())
 }
 
-fn place_order(client: &SyncTDAClient, acct_id: i64) -> Result<(), TDAClientError> {
+fn place_order(client: &SyncTDAClient, acct_id: i64) -> Result<Option<i64>, TDAClientError> {
     let order_spec = equity_buy_limit("AAPL", 1.0, 1000.0).build()?;
 
     /*
@@ -117,16 +120,14 @@ fn place_order(client: &SyncTDAClient, acct_id: i64) -> Result<(), TDAClientErro
     }
     */
 
-    client.place_order(acct_id, &order_spec)?;
-
-    Ok(())
+    client.place_order(acct_id, &order_spec)
 }
 
 fn replace_order(
     client: &SyncTDAClient,
     acct_id: i64,
     order_id: i64,
-) -> Result<(), TDAClientError> {
+) -> Result<Option<i64>, TDAClientError> {
     let order_spec = r#"{
         "orderType": "LIMIT",
         "session": "SEAMLESS",
@@ -145,12 +146,10 @@ fn replace_order(
         ]
       }"#;
 
-    client.replace_order(acct_id, order_id, order_spec)?;
-
-    Ok(())
+    client.replace_order(acct_id, order_id, order_spec)
 }
 
-fn create_saved_order(client: &SyncTDAClient, acct_id: i64) -> Result<(), TDAClientError> {
+fn create_saved_order(client: &SyncTDAClient, acct_id: i64) -> Result<Option<i64>, TDAClientError> {
     let order_spec = r#"{
         "orderType": "LIMIT",
         "session": "SEAMLESS",
@@ -169,9 +168,7 @@ fn create_saved_order(client: &SyncTDAClient, acct_id: i64) -> Result<(), TDACli
         ]
       }"#;
 
-    client.create_saved_order(acct_id, order_spec)?;
-
-    Ok(())
+    client.create_saved_order(acct_id, order_spec)
 }
 
 fn replace_saved_order(
@@ -1,14 +1,18 @@
-use tdameritrade_rust::{output::watchlist::Watchlist, AsyncTDAClient, TDAClientError};
+use tdameritrade_rust::{
+    order_enums::AssetType, output::watchlist::Watchlist, AsyncTDAClient, TDAClientError,
+    WatchlistBuilder, WatchlistItem, WatchlistPatchBuilder,
+};
 mod config;
 
 #[tokio::main]
 async fn main() -> Result<(), TDAClientError> {
     // Create Asynchronous TDAClient
-    let client = AsyncTDAClient::new(
+    let client = AsyncTDAClient::new_async(
         config::client_id(),
         config::redirect_uri(),
         config::token_path(),
-    )?;
+    )
+    .await?;
 
     // Get Account Id
     let acct_id = config::acct_id();
@@ -36,7 +40,8 @@ async fn main() -> Result<(), TDAClientError> {
     println!("{}", res_json.watchlist_id);
 
     // Create Watchlist
-    create_watchlist(&client, acct_id).await?; // Creates Watchlist With Symbols AAPL and AMZN
+    let watchlist_id = create_watchlist(&client, acct_id).await?; // Creates Watchlist With Symbols AAPL and AMZN
+    println!("{:?}", watchlist_id);
 
     // Replace Watchlist
     let replace_watchlist_id = 0;
@@ -55,35 +60,21 @@ async fn main() -> Result<(), TDAClientError> {
     Ok(())
 }
 
-async fn create_watchlist(client: &AsyncTDAClient, acct_id: i64) -> Result<(), TDAClientError> {
+async fn create_watchlist(
+    client: &AsyncTDAClient,
+    acct_id: i64,
+) -> Result<Option<i64>, TDAClientError> {
     // Create Watchlist Spec
-    let create_spec = r#"{
-        "name": "Created Watchlist",
-        "watchlistItems": [
-            {
-                "quantity": 0,
-                "averagePrice": 0,
-                "commission": 0,
-                "purchasedDate": "2022-01-01",
-                "instrument": {
-                    "symbol": "AAPL",
-                    "assetType": "EQUITY"
-                }
-            },
-            {
-                "quantity": 0,
-                "averagePrice": 0,
-                "commission": 0,
-                "purchasedDate": "2022-01-01",
-                "instrument": {
-                    "symbol": "AMZN",
-                    "assetType": "EQUITY"
-                }
-            }
-        ]
-    }"#;
-
-    client.create_watchlist(acct_id, create_spec).await
+    let create_spec = WatchlistBuilder::new("Created Watchlist")
+        .add_watchlist_item(
+            WatchlistItem::new("AAPL", AssetType::Equity).set_purchased_date("2022-01-01"),
+        )
+        .add_watchlist_item(
+            WatchlistItem::new("AMZN", AssetType::Equity).set_purchased_date("2022-01-01"),
+        )
+        .build()?;
+
+    client.create_watchlist(acct_id, &create_spec).await
 }
 
 async fn replace_watchlist(
@@ -91,39 +82,17 @@ async fn replace_watchlist(
     acct_id: i64,
     watchlist_id: i64,
 ) -> Result<(), TDAClientError> {
-    let replace_spec = format!(
-        r#"{{
-        "name": "Replaced Watchlist",
-        "watchlistId": "{}",
-        "watchlistItems": [
-            {{
-                "quantity": 0,
-                "averagePrice": 0,
-                "commission": 0,
-                "purchasedDate": "2022-01-01",
-                "instrument": {{
-                    "symbol": "AMD",
-                    "assetType": "EQUITY"
-                }}
-            }},
-            {{
-                "quantity": 0,
-                "averagePrice": 0,
-                "commission": 0,
-                "purchasedDate": "2022-01-01",
-                "instrument": {{
-                    "symbol": "NVDA",
-                    "assetType": "EQUITY"
-                }}
-            }}
-        ]
-    }}"#,
-        watchlist_id
-    );
+    let replace_spec = WatchlistBuilder::new("Replaced Watchlist")
+        .set_watchlist_id(watchlist_id)
+        .add_symbol("AMD", AssetType::Equity)
+        .add_symbol("NVDA", AssetType::Equity)
+        .build()?;
 
     client
         .replace_watchlist(acct_id, watchlist_id, &replace_spec)
-        .await
+        .await?;
+
+    Ok(())
 }
 
 async fn update_watchlist(
@@ -131,35 +100,12 @@ async fn update_watchlist(
     acct_id: i64,
     watchlist_id: i64,
 ) -> Result<(), TDAClientError> {
-    let update_spec = format!(
-        r#"{{
-        "name": "Updated Watchlist",
-        "watchlistId": "{}",
-        "watchlistItems": [
-            {{
-                "quantity": 0,
-                "averagePrice": 0,
-                "commission": 0,
-                "purchasedDate": "2022-01-01",
-                "instrument": {{
-                    "symbol": "AAPL",
-                    "assetType": "EQUITY"
-                }}
-            }},
-            {{
-                "quantity": 0,
-                "averagePrice": 0,
-                "commission": 0,
-                "purchasedDate": "2022-01-01",
-                "instrument": {{
-                    "symbol": "AMZN",
-                    "assetType": "EQUITY"
-                }}
-            }}
-        ]
-    }}"#,
-        watchlist_id
-    );
+    let update_spec = WatchlistPatchBuilder::new()
+        .set_name("Updated Watchlist")
+        .set_watchlist_id(watchlist_id)
+        .append_watchlist_item(WatchlistItem::new("AAPL", AssetType::Equity))
+        .append_watchlist_item(WatchlistItem::new("AMZN", AssetType::Equity))
+        .build()?;
 
     client
         .update_watchlist(acct_id, watchlist_id, &update_spec)
@@ -1,4 +1,7 @@
-use tdameritrade_rust::{output::watchlist::Watchlist, SyncTDAClient, TDAClientError};
+use tdameritrade_rust::{
+    order_enums::AssetType, output::watchlist::Watchlist, SyncTDAClient, TDAClientError,
+    WatchlistBuilder, WatchlistItem, WatchlistPatchBuilder,
+};
 mod config;
 
 fn main() -> Result<(), TDAClientError> {
@@ -35,7 +38,8 @@ fn main() -> Result<(), TDAClientError> {
     println!("{}", res_json.watchlist_id);
 
     // Create Watchlist
-    create_watchlist(&client, acct_id)?; // Creates Watchlist With Symbols AAPL and AMZN
+    let watchlist_id = create_watchlist(&client, acct_id)?; // Creates Watchlist With Symbols AAPL and AMZN
+    println!("{:?}", watchlist_id);
 
     // Replace Watchlist
     let replace_watchlist_id = 0;
@@ -52,37 +56,18 @@ fn main() -> Result<(), TDAClientError> {
     Ok(())
 }
 
-fn create_watchlist(client: &SyncTDAClient, acct_id: i64) -> Result<(), TDAClientError> {
+fn create_watchlist(client: &SyncTDAClient, acct_id: i64) -> Result<Option<i64>, TDAClientError> {
     // Create Watchlist Spec
-    let create_spec = r#"{
-        "name": "Created Watchlist",
-        "watchlistItems": [
-            {
-                "quantity": 0,
-                "averagePrice": 0,
-                "commission": 0,
-                "purchasedDate": "2022-01-01",
-                "instrument": {
-                    "symbol": "AAPL",
-                    "assetType": "EQUITY"
-                }
-            },
-            {
-                "quantity": 0,
-                "averagePrice": 0,
-                "commission": 0,
-                "purchasedDate": "2022-01-01",
-                "instrument": {
-                    "symbol": "AMZN",
-                    "assetType": "EQUITY"
-                }
-            }
-        ]
-    }"#;
-
-    client.create_watchlist(acct_id, create_spec)?;
-
-    Ok(())
+    let create_spec = WatchlistBuilder::new("Created Watchlist")
+        .add_watchlist_item(
+            WatchlistItem::new("AAPL", AssetType::Equity).set_purchased_date("2022-01-01"),
+        )
+        .add_watchlist_item(
+            WatchlistItem::new("AMZN", AssetType::Equity).set_purchased_date("2022-01-01"),
+        )
+        .build()?;
+
+    client.create_watchlist(acct_id, &create_spec)
 }
 
 fn replace_watchlist(
@@ -90,35 +75,11 @@ fn replace_watchlist(
     acct_id: i64,
     watchlist_id: i64,
 ) -> Result<(), TDAClientError> {
-    let replace_spec = format!(
-        r#"{{
-        "name": "Replaced Watchlist",
-        "watchlistId": "{}",
-        "watchlistItems": [
-            {{
-                "quantity": 0,
-                "averagePrice": 0,
-                "commission": 0,
-                "purchasedDate": "2022-01-01",
-                "instrument": {{
-                    "symbol": "AMD",
-                    "assetType": "EQUITY"
-                }}
-            }},
-            {{
-                "quantity": 0,
-                "averagePrice": 0,
-                "commission": 0,
-                "purchasedDate": "2022-01-01",
-                "instrument": {{
-                    "symbol": "NVDA",
-                    "assetType": "EQUITY"
-                }}
-            }}
-        ]
-    }}"#,
-        watchlist_id
-    );
+    let replace_spec = WatchlistBuilder::new("Replaced Watchlist")
+        .set_watchlist_id(watchlist_id)
+        .add_symbol("AMD", AssetType::Equity)
+        .add_symbol("NVDA", AssetType::Equity)
+        .build()?;
 
     client.replace_watchlist(acct_id, watchlist_id, &replace_spec)?;
 
@@ -130,37 +91,12 @@ fn update_watchlist(
     acct_id: i64,
     watchlist_id: i64,
 ) -> Result<(), TDAClientError> {
-    let update_spec = format!(
-        r#"{{
-        "name": "Updated Watchlist",
-        "watchlistId": "{}",
-        "watchlistItems": [
-            {{
-                "quantity": 0,
-                "averagePrice": 0,
-                "commission": 0,
-                "purchasedDate": "2022-01-01",
-                "instrument": {{
-                    "symbol": "AAPL",
-                    "assetType": "EQUITY"
-                }}
-            }},
-            {{
-                "quantity": 0,
-                "averagePrice": 0,
-                "commission": 0,
-                "purchasedDate": "2022-01-01",
-                "instrument": {{
-                    "symbol": "AMZN",
-                    "assetType": "EQUITY"
-                }}
-            }}
-        ]
-    }}"#,
-        watchlist_id
-    );
-
-    client.update_watchlist(acct_id, watchlist_id, &update_spec)?;
-
-    Ok(())
+    let update_spec = WatchlistPatchBuilder::new()
+        .set_name("Updated Watchlist")
+        .set_watchlist_id(watchlist_id)
+        .append_watchlist_item(WatchlistItem::new("AAPL", AssetType::Equity))
+        .append_watchlist_item(WatchlistItem::new("AMZN", AssetType::Equity))
+        .build()?;
+
+    client.update_watchlist(acct_id, watchlist_id, &update_spec)
 }
@@ -1,85 +1,156 @@
-use crate::token::{read_token_file, NewAccessToken, Token};
+use crate::config::AuthConfig;
+use crate::response;
+use crate::secret::Secret;
+use crate::token::{NewAccessToken, Token};
+use crate::token_store::{TokenStore, TokenStoreLock};
 use crate::TDAClientError;
 use reqwest::Client;
 use std::collections::HashMap;
-use std::fs;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::task;
 
-#[derive(Default, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub struct AsyncAuth {
+    reqwest_client: Client,
     client_id: String,
     redirect_uri: String,
-    token_path: String,
-    access_token: String,
-    refresh_token: String,
+    token_store: Arc<dyn TokenStore>,
+    auth_config: AuthConfig,
+    access_token: Secret,
+    refresh_token: Secret,
     access_token_expire_time: u64,
     refresh_token_expire_time: u64,
+    refresh_lock: Arc<Mutex<()>>,
 }
 
 impl AsyncAuth {
-    // Create New Auth
-    pub fn new(
+    // Create New Auth From A Loaded Token. Loading Is Left To The Caller So It Can Happen Off The Runtime
+    pub fn from_token(
+        reqwest_client: Client,
         client_id: String,
         redirect_uri: String,
-        token_path: String,
-    ) -> Result<Self, TDAClientError> {
-        // Read Token File
-        let token: Token = read_token_file(&token_path)?;
-
-        // Create New Auth
-        let mut auth: AsyncAuth = AsyncAuth::default();
-
-        // Fill Parameters
-        auth.client_id = client_id;
-        auth.redirect_uri = redirect_uri;
-        auth.token_path = token_path;
-        auth.access_token = token.access_token;
-        auth.access_token_expire_time = 0;
-        auth.refresh_token = token.refresh_token;
-        auth.refresh_token_expire_time = token.refresh_token_expires_in;
-
-        // Return Auth
-        Ok(auth)
+        token_store: Arc<dyn TokenStore>,
+        auth_config: AuthConfig,
+        token: Token,
+    ) -> Self {
+        AsyncAuth {
+            reqwest_client,
+            client_id,
+            redirect_uri,
+            token_store,
+            auth_config,
+            access_token_expire_time: token.access_token_expire_time(),
+            refresh_token_expire_time: token.refresh_token_expire_time(),
+            access_token: token.access_token,
+            refresh_token: token.refresh_token,
+            refresh_lock: Arc::new(Mutex::new(())),
+        }
     }
 
     // Get Access Token
-    pub fn get_access_token(&self) -> String {
-        self.access_token.clone()
+    pub fn get_access_token(&self) -> &Secret {
+        &self.access_token
+    }
+
+    // Check If Tokens Are Valid Without Refreshing Them
+    pub fn is_token_valid(&self) -> bool {
+        // Get Current Time
+        let epoch_time: u64 = self.auth_config.clock.now();
+
+        epoch_time + self.auth_config.refresh_token_margin.as_secs()
+            < self.refresh_token_expire_time
+            && epoch_time + self.auth_config.access_token_margin.as_secs()
+                < self.access_token_expire_time
+    }
+
+    // Time Until check_token_validity Will Refresh A Token
+    pub fn next_refresh_in(&self) -> Duration {
+        // Get Current Time
+        let epoch_time: u64 = self.auth_config.clock.now();
+
+        // Earliest Of The Access And Refresh Token Refresh Times
+        let access_refresh_time: u64 = self
+            .access_token_expire_time
+            .saturating_sub(self.auth_config.access_token_margin.as_secs());
+        let refresh_refresh_time: u64 = self
+            .refresh_token_expire_time
+            .saturating_sub(self.auth_config.refresh_token_margin.as_secs());
+
+        Duration::from_secs(
+            access_refresh_time
+                .min(refresh_refresh_time)
+                .saturating_sub(epoch_time),
+        )
     }
 
     // Check Token Validity
     pub async fn check_token_validity(&mut self) -> Result<(), TDAClientError> {
-        // Get Current Time
-        let now: SystemTime = SystemTime::now();
-        let epoch_time: u64 = now
-            .duration_since(UNIX_EPOCH)
-            .expect("Time somehow went backwards")
-            .as_secs();
-
-        // Check If Refresh Token Is Valid
-        if epoch_time > self.refresh_token_expire_time {
-            self.request_new_token("refresh_token").await?;
+        self.refresh_tokens_ahead(Duration::ZERO).await
+    }
+
+    // Refresh Tokens That Will Need Refreshing Within lead. The Background Refresher Uses A Lead So Requests Never Have To Refresh
+    pub async fn refresh_tokens_ahead(&mut self, lead: Duration) -> Result<(), TDAClientError> {
+        // Get Current Time, Moved Ahead By lead
+        let epoch_time: u64 = self.auth_config.clock.now() + lead.as_secs();
+
+        // Expired Refresh Token Can't Be Used To Get A New One
+        if self.auth_config.clock.now() >= self.refresh_token_expire_time {
+            return Err(self.notify_reauth(TDAClientError::RefreshTokenExpired));
         }
 
-        // Check If Access Token Is Valid
-        if epoch_time > self.access_token_expire_time {
-            self.request_new_token("access_token").await?;
+        // Replace Refresh Token Before It Expires
+        if epoch_time + self.auth_config.refresh_token_margin.as_secs()
+            >= self.refresh_token_expire_time
+        {
+            self.request_new_token("refresh_token", lead)
+                .await
+                .map_err(|err| self.notify_reauth(err))?;
+        }
+
+        // Refresh Access Token Before It Expires
+        if epoch_time + self.auth_config.access_token_margin.as_secs()
+            >= self.access_token_expire_time
+        {
+            self.request_new_token("access_token", lead)
+                .await
+                .map_err(|err| self.notify_reauth(err))?;
         }
 
         Ok(())
     }
 
-    // Request New Token
-    pub async fn request_new_token(&mut self, token_type: &str) -> Result<(), TDAClientError> {
-        // Create Client
-        let reqwest_client: Client = Client::new();
+    // Request New Token. Auth Is Only Updated After The New Token Is Saved
+    pub async fn request_new_token(
+        &mut self,
+        token_type: &str,
+        lead: Duration,
+    ) -> Result<(), TDAClientError> {
+        // Only One Clone Refreshes At A Time, Even If The Token Store Doesn't Lock
+        let refresh_lock: Arc<Mutex<()>> = self.refresh_lock.clone();
+        let _refresh_guard = refresh_lock.lock().await;
+
+        // Only One Process Refreshes At A Time
+        let _lock: TokenStoreLock = self.lock_token_store().await?;
+
+        // Reload Token. Another Clone Or Process May Have Refreshed It While This One Waited For The Lock
+        let stored_token: Token = self.load_token().await?;
 
-        // Create Hashmap To Store Params
+        if self.is_refreshed_elsewhere(token_type, &stored_token, lead) {
+            self.update_tokens(&stored_token);
+            return Ok(());
+        }
+
+        // Create Hashmap To Store Params. Stored Refresh Token Is The Newest One
         let mut params: HashMap<String, String> = HashMap::new();
 
         // Parameters
         params.insert("grant_type".into(), "refresh_token".into());
-        params.insert("refresh_token".into(), self.refresh_token.clone());
+        params.insert(
+            "refresh_token".into(),
+            stored_token.refresh_token.expose_secret().to_string(),
+        );
         params.insert("client_id".into(), self.client_id.clone());
         params.insert("redirect_uri".into(), self.redirect_uri.clone());
 
@@ -88,81 +159,127 @@ impl AsyncAuth {
             params.insert("access_type".into(), "offline".into());
         }
 
-        // Create Url
-        let url: String = "https://api.tdameritrade.com/v1/oauth2/token".into();
-
         // Request New Token
-        let res = reqwest_client.post(url).form(&params).send().await?;
+        let res = self
+            .reqwest_client
+            .post(&self.auth_config.auth_url)
+            .form(&params)
+            .send()
+            .await?;
+
+        // Get Response
+        let status = res.status();
+        let headers = res.headers().clone();
+        let res_text: String = res.text().await?;
+
+        // Refresh Failed
+        if !status.is_success() {
+            return Err(TDAClientError::from_token_response(
+                status, &headers, &res_text,
+            ));
+        }
+
+        // Get Current Time
+        let epoch_time: u64 = self.auth_config.clock.now();
+
+        let token: Token = if token_type == "access_token" {
+            // Get JSON Response For Access Token
+            let res_json: NewAccessToken = response::parse_json("POST oauth2/token", &res_text)?;
 
-        // Get Response Text
-        let res_text: String = res.text().await.expect("Unable To Get Post Text");
+            // Replace Access Token
+            let mut token: Token = stored_token;
+            token.replace_access_token_expire_time(epoch_time + res_json.expires_in.max(0) as u64);
+            token.replace_access_token(res_json.access_token);
+
+            token
+        } else {
+            // Get JSON Response For Refresh Token
+            let mut token: Token = response::parse_json("POST oauth2/token", &res_text)?;
+
+            // Set Expire Times
+            token.set_expire_times(epoch_time);
+
+            token
+        };
+
+        // Save Token
+        self.save_token(token.clone()).await?;
+
+        // Update Auth Values
+        self.update_tokens(&token);
+
+        Ok(())
+    }
+
+    // Whether The Stored Token Was Refreshed By Another Process And Doesn't Need Refreshing
+    fn is_refreshed_elsewhere(
+        &self,
+        token_type: &str,
+        stored_token: &Token,
+        lead: Duration,
+    ) -> bool {
+        // Get Current Time, Moved Ahead By lead
+        let epoch_time: u64 = self.auth_config.clock.now() + lead.as_secs();
 
-        // Get JSON Response For Access Token
         if token_type == "access_token" {
-            match serde_json::from_str::<NewAccessToken>(&res_text) {
-                Ok(res_json) => {
-                    // Get Current Time
-                    let now: SystemTime = SystemTime::now();
-                    let epoch_time: u64 = now
-                        .duration_since(UNIX_EPOCH)
-                        .expect("Time somehow went backwards")
-                        .as_secs();
-
-                    // Update Auth Values
-                    self.access_token = res_json.access_token.clone();
-                    self.access_token_expire_time = epoch_time + 1500; // 25 Minutes Until Access Token Refresh
-
-                    // Read Token File
-                    let mut token: Token = read_token_file(&self.token_path)?;
-
-                    // Replace Access Token
-                    token.replace_access_token(res_json.access_token);
-
-                    // Write To File
-                    fs::write(self.token_path.clone(), token.to_string())?
-                }
-
-                // Too Many Calls To TDAmeritrade Servers
-                _ => (),
-            }
+            stored_token.access_token != self.access_token
+                && epoch_time + self.auth_config.access_token_margin.as_secs()
+                    < stored_token.access_token_expire_time()
+        } else {
+            stored_token.refresh_token != self.refresh_token
+                && epoch_time + self.auth_config.refresh_token_margin.as_secs()
+                    < stored_token.refresh_token_expire_time()
         }
+    }
 
-        // Get JSON Response For Refresh Token
-        if token_type == "refresh_token" {
-            match serde_json::from_str::<Token>(&res_text) {
-                Ok(res_json) => {
-                    // Get Current Time
-                    let now: SystemTime = SystemTime::now();
-                    let epoch_time: u64 = now
-                        .duration_since(UNIX_EPOCH)
-                        .expect("Time somehow went backwards")
-                        .as_secs();
-
-                    // Update Auth Values
-                    self.access_token = res_json.access_token.clone();
-                    self.access_token_expire_time = epoch_time + 1500; // 25 Minutes Until Access Token Refresh
-                    self.refresh_token = res_json.refresh_token.clone();
-                    self.refresh_token_expire_time = epoch_time + 6480000; // Refresh Token Expires After 75 Days
-
-                    // Read Token File
-                    let mut token: Token = read_token_file(&self.token_path)?;
-
-                    // Replace Tokens
-                    token.replace_access_token(res_json.access_token);
-                    token.replace_refresh_token(res_json.refresh_token);
-
-                    // Replace Refresh Token Expire Time
-                    token.replace_refresh_token_expire_time(epoch_time + 6480000); // Refresh Token Expires After 75 Days
-
-                    // Write To File
-                    fs::write(self.token_path.clone(), token.to_string())?
-                }
-
-                // Too Many Calls To TDAmeritrade Servers
-                _ => (),
+    // Check If Both Auths Hold The Same Tokens
+    pub fn holds_same_tokens(&self, other: &Self) -> bool {
+        self.access_token == other.access_token && self.refresh_token == other.refresh_token
+    }
+
+    // Use Tokens And Expire Times From Token
+    fn update_tokens(&mut self, token: &Token) {
+        self.access_token = token.access_token.clone();
+        self.access_token_expire_time = token.access_token_expire_time();
+        self.refresh_token = token.refresh_token.clone();
+        self.refresh_token_expire_time = token.refresh_token_expire_time();
+    }
+
+    // Load Token Without Blocking The Runtime
+    async fn load_token(&self) -> Result<Token, TDAClientError> {
+        let token_store: Arc<dyn TokenStore> = self.token_store.clone();
+
+        task::spawn_blocking(move || token_store.load())
+            .await
+            .map_err(|err| TDAClientError::TokenStoreError(err.to_string()))?
+    }
+
+    // Lock Token Store Without Blocking The Runtime
+    async fn lock_token_store(&self) -> Result<TokenStoreLock, TDAClientError> {
+        let token_store: Arc<dyn TokenStore> = self.token_store.clone();
+
+        task::spawn_blocking(move || token_store.lock())
+            .await
+            .map_err(|err| TDAClientError::TokenStoreError(err.to_string()))?
+    }
+
+    // Save Token Without Blocking The Runtime
+    async fn save_token(&self, token: Token) -> Result<(), TDAClientError> {
+        let token_store: Arc<dyn TokenStore> = self.token_store.clone();
+
+        task::spawn_blocking(move || token_store.save(&token))
+            .await
+            .map_err(|err| TDAClientError::TokenStoreError(err.to_string()))?
+    }
+
+    // Run Reauth Callback If The Error Means A New Token Must Be Created
+    fn notify_reauth(&self, err: TDAClientError) -> TDAClientError {
+        if err.requires_reauth() {
+            if let Some(reauth_callback) = &self.auth_config.reauth_callback {
+                reauth_callback.call(&err);
             }
         }
 
-        Ok(())
+        err
     }
 }
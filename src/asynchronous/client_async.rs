@@ -1,46 +1,111 @@
-use crate::asynchronous::{auth_async::AsyncAuth, requests_async};
+use crate::asynchronous::{
+    auth_async::AsyncAuth, refresher_async::AsyncTokenRefresher, requests_async,
+};
 use crate::builders::{OptionChain, PriceHistory};
+use crate::client_builder::TDAClientBuilder;
+use crate::config::ClientConfig;
 use crate::error::TDAClientError;
+use crate::market_data::MarketDataRequest;
+use crate::response;
+use crate::secret::Secret;
+use crate::token_store::TokenStore;
 use itertools::Itertools;
 use reqwest::Client;
 use std::collections::HashMap;
+use std::sync::Arc;
 use tokio::sync::RwLock;
 
+/// Clones share the same auth state, so a token refreshed by one clone is used by all of them
+#[derive(Clone)]
 pub struct AsyncTDAClient {
     reqwest_client: Client,
-    auth: RwLock<AsyncAuth>,
+    config: ClientConfig,
+    auth: Arc<RwLock<AsyncAuth>>,
 }
 
 impl AsyncTDAClient {
     /// Create New Asynchronous TDAClient
+    ///
+    /// Use `TDAClientBuilder` to configure URLs, timeouts, user agent, proxy, or the reqwest client
+    ///
+    /// Reads the token file on the calling thread. Use `new_async` inside a tokio runtime to keep the runtime unblocked
     pub fn new(
         client_id: String,
         redirect_uri: String,
         token_path: String,
     ) -> Result<AsyncTDAClient, TDAClientError> {
-        // Create Auth Client
-        let auth: AsyncAuth = AsyncAuth::new(client_id, redirect_uri, token_path)?;
+        TDAClientBuilder::new(client_id, redirect_uri, token_path).build_async()
+    }
 
-        // Create Reqwest Client
-        let reqwest_client: Client = Client::new();
+    /// Create New Asynchronous TDAClient, reading the token file on tokio's blocking thread pool
+    pub async fn new_async(
+        client_id: String,
+        redirect_uri: String,
+        token_path: String,
+    ) -> Result<AsyncTDAClient, TDAClientError> {
+        TDAClientBuilder::new(client_id, redirect_uri, token_path)
+            .build_async_nonblocking()
+            .await
+    }
 
-        // Create New AsyncTDAClient
-        Ok(AsyncTDAClient {
+    /// Create New Asynchronous TDAClient That Loads And Saves Its Token With A `TokenStore`
+    ///
+    /// Loads the token on the calling thread. Use `with_token_store_async` inside a tokio runtime to keep the runtime unblocked
+    pub fn with_token_store(
+        client_id: String,
+        redirect_uri: String,
+        token_store: impl TokenStore + 'static,
+    ) -> Result<AsyncTDAClient, TDAClientError> {
+        TDAClientBuilder::with_token_store(client_id, redirect_uri, token_store).build_async()
+    }
+
+    /// Create New Asynchronous TDAClient That Loads And Saves Its Token With A `TokenStore`, loading it on tokio's blocking thread pool
+    pub async fn with_token_store_async(
+        client_id: String,
+        redirect_uri: String,
+        token_store: impl TokenStore + 'static,
+    ) -> Result<AsyncTDAClient, TDAClientError> {
+        TDAClientBuilder::with_token_store(client_id, redirect_uri, token_store)
+            .build_async_nonblocking()
+            .await
+    }
+
+    // Create AsyncTDAClient From Configured Parts
+    pub(crate) fn from_parts(
+        reqwest_client: Client,
+        auth: AsyncAuth,
+        config: ClientConfig,
+    ) -> Self {
+        AsyncTDAClient {
             reqwest_client,
-            auth: RwLock::new(auth),
-        })
+            config,
+            auth: Arc::new(RwLock::new(auth)),
+        }
     }
 
-    /// Clones AsyncTDAClient
-    pub async fn clone(&self) -> Self {
-        // Create a new `AsyncTDAClient` instance with the same values as the current instance.
-        let clone = Self {
-            reqwest_client: self.reqwest_client.clone(),
-            auth: RwLock::new(self.auth.read().await.clone()),
-        };
+    // Get Access Token, Refreshing It First If It Expired
+    async fn get_access_token(&self) -> Result<Secret, TDAClientError> {
+        // Token Is Still Valid
+        {
+            let auth = self.auth.read().await;
+
+            if auth.is_token_valid() {
+                return Ok(auth.get_access_token().clone());
+            }
+        }
+
+        // Only One Clone Refreshes At A Time. The Others Wait, Then Reuse The New Token
+        let mut auth = self.auth.write().await;
+        auth.check_token_validity().await?;
 
-        // Return the cloned `AsyncTDAClient` instance.
-        clone
+        Ok(auth.get_access_token().clone())
+    }
+
+    /// Start refreshing the token in the background shortly before it expires, so requests don't wait on token refreshes
+    ///
+    /// The refresher stops when the returned handle is shut down or dropped and must be started inside a tokio runtime
+    pub fn start_token_refresher(&self) -> AsyncTokenRefresher {
+        AsyncTokenRefresher::start(self.auth.clone())
     }
 
     //// Accounts ////
@@ -57,13 +122,8 @@ impl AsyncTDAClient {
         acct_id: i64,
         fields: Option<&Vec<&str>>,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Create HashMap To Store Parameters
         let mut params: HashMap<String, String> = HashMap::new();
@@ -80,7 +140,14 @@ impl AsyncTDAClient {
         let url: String = format!("accounts/{}", acct_id);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
+        .await
     }
 
     /// Account balances, positions, and orders for all linked accounts
@@ -90,13 +157,8 @@ impl AsyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/account-access/apis/get/accounts-0
     pub async fn get_accounts(&self, fields: Option<&Vec<&str>>) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Create HashMap To Store Parameters
         let mut params: HashMap<String, String> = HashMap::new();
@@ -113,7 +175,14 @@ impl AsyncTDAClient {
         let url: String = "accounts".into();
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
+        .await
     }
 
     //// Instruments ////
@@ -135,26 +204,21 @@ impl AsyncTDAClient {
         symbol: &str,
         projection: &str,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
-        // Create HashMap To Store Parameters
-        let mut params: HashMap<String, String> = HashMap::new();
-
-        // Parameters
-        params.insert("symbol".into(), symbol.into());
-        params.insert("projection".into(), projection.into());
-
-        // Format URL
-        let url: String = "instruments".into();
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::search_instruments(symbol, projection);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            request.params,
+            request.url,
+        )
+        .await
     }
 
     /// Get an instrument by CUSIP
@@ -164,22 +228,21 @@ impl AsyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/instruments/apis/get/instruments/%7Bcusip%7D
     pub async fn get_instrument(&self, cusip: &str) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
-        // Create HashMap To Store Parameters
-        let params: HashMap<String, String> = HashMap::new();
-
-        // Format URL
-        let url: String = format!("instruments/{}", cusip);
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::get_instrument(cusip);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            request.params,
+            request.url,
+        )
+        .await
     }
 
     //// Market Hours ////
@@ -196,29 +259,22 @@ impl AsyncTDAClient {
         markets: &Vec<&str>,
         date: &str,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
-
-        // Create HashMap To Store Parameters
-        let mut params: HashMap<String, String> = HashMap::new();
-
-        // Convert Vector To String
-        let cleaned_markets: String = markets.join(",");
+        let access_token: Secret = self.get_access_token().await?;
 
-        // Parameters
-        params.insert("markets".into(), cleaned_markets);
-        params.insert("date".into(), date.into());
-
-        // Format URL
-        let url: String = "marketdata/hours".into();
+        // Create Request
+        let request: MarketDataRequest =
+            MarketDataRequest::get_hours_for_multiple_markets(markets, date);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            request.params,
+            request.url,
+        )
+        .await
     }
 
     /// Retrieve market hours for specified single market
@@ -233,25 +289,22 @@ impl AsyncTDAClient {
         market: &str,
         date: &str,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
-        // Create HashMap To Store Parameters
-        let mut params: HashMap<String, String> = HashMap::new();
-
-        // Parameter
-        params.insert("date".into(), date.into());
-
-        // Format URL
-        let url: String = format!("marketdata/{}/hours", market);
+        // Create Request
+        let request: MarketDataRequest =
+            MarketDataRequest::get_hours_for_single_market(market, date);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            request.params,
+            request.url,
+        )
+        .await
     }
 
     //// Movers ////
@@ -270,26 +323,21 @@ impl AsyncTDAClient {
         direction: &str,
         change: &str,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
-        // Create HashMap To Store Parameters
-        let mut params: HashMap<String, String> = HashMap::new();
-
-        // Parameters
-        params.insert("direction".into(), direction.into());
-        params.insert("change".into(), change.into());
-
-        // Format URL
-        let url: String = format!("marketdata/{}/movers", index);
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::get_movers(index, direction, change);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            request.params,
+            request.url,
+        )
+        .await
     }
 
     //// Option Chains ////
@@ -320,80 +368,21 @@ impl AsyncTDAClient {
         &self,
         option_params: &OptionChain,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
-        // Create HashMap To Store Parameters
-        let mut params: HashMap<String, String> = HashMap::new();
-
-        // Optional Parameters
-        if option_params.strike_count != -1 {
-            params.insert("strikeCount".into(), option_params.strike_count.to_string());
-        }
-
-        if option_params.interval != -1.0 {
-            params.insert("interval".into(), option_params.interval.to_string());
-        }
-
-        if option_params.strike != -1.0 {
-            params.insert("strike".into(), option_params.strike.to_string());
-        }
-
-        if option_params.from_date != "" {
-            params.insert("fromDate".into(), option_params.from_date.clone());
-        }
-
-        if option_params.to_date != "" {
-            params.insert("toDate".into(), option_params.to_date.clone());
-        }
-
-        if option_params.volatility != -1.0 {
-            params.insert("volatility".into(), option_params.volatility.to_string());
-        }
-
-        if option_params.underlying_price != -1.0 {
-            params.insert(
-                "underlyingPrice".into(),
-                option_params.underlying_price.to_string(),
-            );
-        }
-
-        if option_params.interest_rate != -1.0 {
-            params.insert(
-                "interestRate".into(),
-                option_params.interest_rate.to_string(),
-            );
-        }
-
-        if option_params.days_to_expiration != -1 {
-            params.insert(
-                "daysToExpiration".into(),
-                option_params.days_to_expiration.to_string(),
-            );
-        }
-
-        // Required Parameters
-        params.insert("symbol".into(), option_params.symbol.clone());
-        params.insert("contractType".into(), option_params.contract_type.clone());
-        params.insert(
-            "includeQuotes".into(),
-            option_params.include_quotes.to_string(),
-        );
-        params.insert("strategy".into(), option_params.strategy.clone());
-        params.insert("range".into(), option_params.range.clone());
-        params.insert("expMonth".into(), option_params.expiration_month.clone());
-        params.insert("optionType".into(), option_params.option_type.clone());
-
-        // Format URL
-        let url: String = "marketdata/chains".into();
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::get_option_chain(option_params);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            request.params,
+            request.url,
+        )
+        .await
     }
 
     //// Preferences ////
@@ -405,13 +394,8 @@ impl AsyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/user-principal/apis/get/accounts/%7BaccountId%7D/preferences-0
     pub async fn get_preferences(&self, acct_id: i64) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Create HashMap To Store Parameters
         let params: HashMap<String, String> = HashMap::new();
@@ -420,7 +404,14 @@ impl AsyncTDAClient {
         let url: String = format!("accounts/{}/preferences", acct_id);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
+        .await
     }
 
     /// Update preferences for a specific account. Please note that the directOptionsRouting and directEquityRouting values cannot be modified via this operation
@@ -435,13 +426,8 @@ impl AsyncTDAClient {
         acct_id: i64,
         preference_spec: &str,
     ) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Convert Preference Spec To String
         let body: String = preference_spec.into();
@@ -450,7 +436,9 @@ impl AsyncTDAClient {
         let url: String = format!("accounts/{}/preferences", acct_id);
 
         // Update Preferences
-        requests_async::put(&self.reqwest_client, access_token, body, url).await
+        requests_async::put(&self.reqwest_client, &self.config, access_token, body, url).await?;
+
+        Ok(())
     }
 
     //// Price History ////
@@ -473,47 +461,21 @@ impl AsyncTDAClient {
         &self,
         history_params: &PriceHistory,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
-
-        // Create HashMap To Store Parameters
-        let mut params: HashMap<String, String> = HashMap::new();
+        let access_token: Secret = self.get_access_token().await?;
 
-        // Optional Parameters
-        if history_params.period != -1 {
-            params.insert("period".into(), history_params.period.to_string());
-        }
-
-        if history_params.start_date != -1 {
-            params.insert("startDate".into(), history_params.start_date.to_string());
-        }
-
-        if history_params.end_date != -1 {
-            params.insert("endDate".into(), history_params.end_date.to_string());
-        }
-
-        // Required Parameters
-        params.insert("periodType".into(), history_params.period_type.clone());
-        params.insert(
-            "frequencyType".into(),
-            history_params.frequency_type.clone(),
-        );
-        params.insert("frequency".into(), history_params.frequency.to_string());
-        params.insert(
-            "needExtendedHoursData".into(),
-            history_params.need_extended_hours_data.to_string(),
-        );
-
-        // Format URL
-        let url: String = format!("marketdata/{}/pricehistory", history_params.symbol);
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::get_price_history(history_params);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            request.params,
+            request.url,
+        )
+        .await
     }
 
     //// Quotes ////
@@ -525,22 +487,21 @@ impl AsyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/quotes/apis/get/marketdata/%7Bsymbol%7D/quotes
     pub async fn get_quote(&self, symbol: &str) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
-        // Create HashMap To Store Parameters
-        let params: HashMap<String, String> = HashMap::new();
-
-        // Format URL
-        let url: String = format!("marketdata/{}/quotes", symbol);
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::get_quote(symbol);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            request.params,
+            request.url,
+        )
+        .await
     }
 
     /// Get quote for one or more symbols
@@ -550,28 +511,21 @@ impl AsyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/quotes/apis/get/marketdata/quotes
     pub async fn get_quotes(&self, symbols: &Vec<&str>) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
-
-        // Create HashMap To Store Parameters
-        let mut params: HashMap<String, String> = HashMap::new();
+        let access_token: Secret = self.get_access_token().await?;
 
-        // Convert Vector To String
-        let cleaned_symbols: String = symbols.join(",");
-
-        // Parameter
-        params.insert("symbol".into(), cleaned_symbols);
-
-        // Format URL
-        let url: String = "marketdata/quotes".into();
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::get_quotes(symbols);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            request.params,
+            request.url,
+        )
+        .await
     }
 
     //// Trading ////
@@ -584,13 +538,8 @@ impl AsyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/account-access/apis/get/accounts/%7BaccountId%7D/orders/%7BorderId%7D-0
     pub async fn get_order(&self, acct_id: i64, order_id: i64) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Create HashMap To Store Parameters
         let params: HashMap<String, String> = HashMap::new();
@@ -599,7 +548,14 @@ impl AsyncTDAClient {
         let url: String = format!("accounts/{}/orders/{}", acct_id, order_id);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
+        .await
     }
 
     /// Orders for a specific account
@@ -620,13 +576,8 @@ impl AsyncTDAClient {
         to_entered_time: &str,
         status: &str,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Create HashMap To Store Parameters
         let mut params: HashMap<String, String> = HashMap::new();
@@ -641,7 +592,14 @@ impl AsyncTDAClient {
         let url: String = format!("accounts/{}/orders", acct_id);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
+        .await
     }
 
     /// All orders for a specific account or, if acct_id isn't specified, orders will be returned for all linked accounts
@@ -662,13 +620,8 @@ impl AsyncTDAClient {
         to_entered_time: &str,
         status: &str,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Create HashMap To Store Parameters
         let mut params: HashMap<String, String> = HashMap::new();
@@ -688,11 +641,20 @@ impl AsyncTDAClient {
         let url: String = "orders".into();
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
+        .await
     }
 
     /// Place an order for a specific account. Order throttle limits may apply
     ///
+    /// Returns the new order's ID, or `None` if the API accepted it without a Location header holding the ID
+    ///
     /// Parameters
     /// - acct_id: Account number
     /// - order_spec: Order body
@@ -700,14 +662,13 @@ impl AsyncTDAClient {
     /// Order Examples: https://developer.tdameritrade.com/content/place-order-samples
     ///
     /// Official Documentation: https://developer.tdameritrade.com/account-access/apis/post/accounts/%7BaccountId%7D/orders-0
-    pub async fn place_order(&self, acct_id: i64, order_spec: &str) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
+    pub async fn place_order(
+        &self,
+        acct_id: i64,
+        order_spec: &str,
+    ) -> Result<Option<i64>, TDAClientError> {
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Convert Order Spec To String
         let body: String = order_spec.into();
@@ -716,11 +677,18 @@ impl AsyncTDAClient {
         let url: String = format!("accounts/{}/orders", acct_id);
 
         // Place Order
-        requests_async::post(&self.reqwest_client, access_token, body, url).await
+        let location: Option<String> =
+            requests_async::post(&self.reqwest_client, &self.config, access_token, body, url)
+                .await?;
+
+        // Return New Order ID
+        Ok(response::parse_location_id(location))
     }
 
     /// Replace an existing order for an account. The existing order will be replaced by the new order. Once replaced, the old order will be canceled and a new order will be created. Order throttle limits may apply
     ///
+    /// Returns the new order's ID, or `None` if the API accepted it without a Location header holding the ID
+    ///
     /// Parameters
     /// - acct_id: Account number
     /// - order_id: Order number
@@ -734,14 +702,9 @@ impl AsyncTDAClient {
         acct_id: i64,
         order_id: i64,
         order_spec: &str,
-    ) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
+    ) -> Result<Option<i64>, TDAClientError> {
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Convert Order Spec To String
         let body: String = order_spec.into();
@@ -750,7 +713,12 @@ impl AsyncTDAClient {
         let url: String = format!("accounts/{}/orders/{}", acct_id, order_id);
 
         // Replace Order
-        requests_async::put(&self.reqwest_client, access_token, body, url).await
+        let location: Option<String> =
+            requests_async::put(&self.reqwest_client, &self.config, access_token, body, url)
+                .await?;
+
+        // Return New Order ID
+        Ok(response::parse_location_id(location))
     }
 
     /// Cancel a specific order for a specific account. Order throttle limits may apply
@@ -761,19 +729,14 @@ impl AsyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/account-access/apis/delete/accounts/%7BaccountId%7D/orders/%7BorderId%7D-0
     pub async fn cancel_order(&self, acct_id: i64, order_id: i64) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Format URL
         let url: String = format!("accounts/{}/orders/{}", acct_id, order_id);
 
         // Cancel Order
-        requests_async::delete(&self.reqwest_client, access_token, url).await
+        requests_async::delete(&self.reqwest_client, &self.config, access_token, url).await
     }
 
     /// Specific saved order by its ID, for a specific account
@@ -788,13 +751,8 @@ impl AsyncTDAClient {
         acct_id: i64,
         order_id: i64,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Create HashMap To Store Parameters
         let params: HashMap<String, String> = HashMap::new();
@@ -803,7 +761,14 @@ impl AsyncTDAClient {
         let url: String = format!("accounts/{}/savedorders/{}", acct_id, order_id);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
+        .await
     }
 
     /// Saved orders for a specific account
@@ -813,13 +778,8 @@ impl AsyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/account-access/apis/get/accounts/%7BaccountId%7D/savedorders-0
     pub async fn get_saved_orders_by_path(&self, acct_id: i64) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Create HashMap To Store Parameters
         let params: HashMap<String, String> = HashMap::new();
@@ -828,11 +788,20 @@ impl AsyncTDAClient {
         let url: String = format!("accounts/{}/savedorders", acct_id);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
+        .await
     }
 
     /// Save an order for a specific account
     ///
+    /// Returns the new saved order's ID, or `None` if the API accepted it without a Location header holding the ID
+    ///
     /// Parameters
     /// - acct_id: Account number
     /// - order_spec: Order body
@@ -844,14 +813,9 @@ impl AsyncTDAClient {
         &self,
         acct_id: i64,
         order_spec: &str,
-    ) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
+    ) -> Result<Option<i64>, TDAClientError> {
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Convert Order Spec To String
         let body: String = order_spec.into();
@@ -860,7 +824,12 @@ impl AsyncTDAClient {
         let url: String = format!("accounts/{}/savedorders", acct_id);
 
         // Create Saved Order
-        requests_async::post(&self.reqwest_client, access_token, body, url).await
+        let location: Option<String> =
+            requests_async::post(&self.reqwest_client, &self.config, access_token, body, url)
+                .await?;
+
+        // Return New Saved Order ID
+        Ok(response::parse_location_id(location))
     }
 
     /// Replace an existing saved order for an account. The existing saved order will be replaced by the new order
@@ -879,13 +848,8 @@ impl AsyncTDAClient {
         order_id: i64,
         order_spec: &str,
     ) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Convert Order Spec To String
         let body: String = order_spec.into();
@@ -894,7 +858,9 @@ impl AsyncTDAClient {
         let url: String = format!("accounts/{}/savedorders/{}", acct_id, order_id);
 
         // Replace Order
-        requests_async::put(&self.reqwest_client, access_token, body, url).await
+        requests_async::put(&self.reqwest_client, &self.config, access_token, body, url).await?;
+
+        Ok(())
     }
 
     /// Delete a specific saved order for a specific account
@@ -909,19 +875,14 @@ impl AsyncTDAClient {
         acct_id: i64,
         order_id: i64,
     ) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Format URL
         let url: String = format!("accounts/{}/savedorders/{}", acct_id, order_id);
 
         // Cancel Order
-        requests_async::delete(&self.reqwest_client, access_token, url).await
+        requests_async::delete(&self.reqwest_client, &self.config, access_token, url).await
     }
 
     //// Transaction History ////
@@ -938,13 +899,8 @@ impl AsyncTDAClient {
         acct_id: i64,
         transaction_id: i64,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Create HashMap To Store Parameters
         let params: HashMap<String, String> = HashMap::new();
@@ -953,7 +909,14 @@ impl AsyncTDAClient {
         let url: String = format!("accounts/{}/transactions/{}", acct_id, transaction_id);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
+        .await
     }
 
     /// Transactions for a specific account
@@ -974,13 +937,8 @@ impl AsyncTDAClient {
         start_date: &str,
         end_date: &str,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Create HashMap To Store Parameters
         let mut params: HashMap<String, String> = HashMap::new();
@@ -999,7 +957,14 @@ impl AsyncTDAClient {
         let url: String = format!("accounts/{}/transactions", acct_id);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
+        .await
     }
 
     //// User Info ////
@@ -1012,15 +977,10 @@ impl AsyncTDAClient {
     /// Official Documentation: https://developer.tdameritrade.com/user-principal/apis/get/userprincipals/streamersubscriptionkeys-0
     pub async fn get_streamer_subscription_keys(
         &self,
-        acct_ids: &Vec<i64>,
+        acct_ids: &[i64],
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Create HashMap To Store Parameters
         let mut params: HashMap<String, String> = HashMap::new();
@@ -1035,7 +995,14 @@ impl AsyncTDAClient {
         let url: String = "userprincipals/streamersubscriptionkeys".into();
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
+        .await
     }
 
     /// User principal details
@@ -1048,13 +1015,8 @@ impl AsyncTDAClient {
         &self,
         fields: Option<&Vec<&str>>,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Create HashMap To Store Parameters
         let mut params: HashMap<String, String> = HashMap::new();
@@ -1071,7 +1033,14 @@ impl AsyncTDAClient {
         let url: String = "userprincipals".into();
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
+        .await
     }
 
     //// Watchlist ////
@@ -1088,13 +1057,8 @@ impl AsyncTDAClient {
         acct_id: i64,
         watchlist_id: i64,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Create HashMap To Store Parameters
         let params: HashMap<String, String> = HashMap::new();
@@ -1103,7 +1067,14 @@ impl AsyncTDAClient {
         let url: String = format!("accounts/{}/watchlists/{}", acct_id, watchlist_id);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
+        .await
     }
 
     /// All watchlists of an account
@@ -1116,13 +1087,8 @@ impl AsyncTDAClient {
         &self,
         acct_id: i64,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Create HashMap To Store Parameters
         let params: HashMap<String, String> = HashMap::new();
@@ -1131,20 +1097,22 @@ impl AsyncTDAClient {
         let url: String = format!("accounts/{}/watchlists", acct_id);
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
+        .await
     }
 
     /// All watchlists for all of the user's linked accounts
     ///
     /// Official Documentation: https://developer.tdameritrade.com/watchlist/apis/get/accounts/watchlists-0
     pub async fn get_watchlists_for_multiple_accounts(&self) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Create HashMap To Store Parameters
         let params: HashMap<String, String> = HashMap::new();
@@ -1153,28 +1121,32 @@ impl AsyncTDAClient {
         let url: String = "accounts/watchlists".into();
 
         // Return String Response
-        requests_async::get(&self.reqwest_client, access_token, params, url).await
+        requests_async::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
+        .await
     }
 
     /// Create watchlist for specific account. This method does not verify that the symbol or asset type are valid
     ///
+    /// Returns the new watchlist's ID, or `None` if the API accepted it without a Location header holding the ID
+    ///
     /// Parameters
     /// - acct_id: Account number
-    /// - watchlist_spec: Watchlist body
+    /// - watchlist_spec: Watchlist body. Build with `WatchlistBuilder`
     ///
     /// Official Documentation: https://developer.tdameritrade.com/watchlist/apis/post/accounts/%7BaccountId%7D/watchlists-0
     pub async fn create_watchlist(
         &self,
         acct_id: i64,
         watchlist_spec: &str,
-    ) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
+    ) -> Result<Option<i64>, TDAClientError> {
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Convert Watchlist Spec To String
         let body: String = watchlist_spec.into();
@@ -1183,7 +1155,12 @@ impl AsyncTDAClient {
         let url: String = format!("accounts/{}/watchlists", acct_id);
 
         // Create Watchlist
-        requests_async::post(&self.reqwest_client, access_token, body, url).await
+        let location: Option<String> =
+            requests_async::post(&self.reqwest_client, &self.config, access_token, body, url)
+                .await?;
+
+        // Return New Watchlist ID
+        Ok(response::parse_location_id(location))
     }
 
     /// Replace watchlist for a specific account. This method does not verify that the symbol or asset type are valid
@@ -1191,7 +1168,7 @@ impl AsyncTDAClient {
     /// Parameters
     /// - acct_id: Account number
     /// - watchlist_id: Watchlist number
-    /// - watchlist_spec: Watchlist body
+    /// - watchlist_spec: Watchlist body. Build with `WatchlistBuilder`
     ///
     /// Official Documentation: https://developer.tdameritrade.com/watchlist/apis/put/accounts/%7BaccountId%7D/watchlists/%7BwatchlistId%7D-0
     pub async fn replace_watchlist(
@@ -1200,13 +1177,8 @@ impl AsyncTDAClient {
         watchlist_id: i64,
         watchlist_spec: &str,
     ) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Convert Watchlist Spec To String
         let body: String = watchlist_spec.into();
@@ -1215,7 +1187,9 @@ impl AsyncTDAClient {
         let url: String = format!("accounts/{}/watchlists/{}", acct_id, watchlist_id);
 
         // Replace Watchlist
-        requests_async::put(&self.reqwest_client, access_token, body, url).await
+        requests_async::put(&self.reqwest_client, &self.config, access_token, body, url).await?;
+
+        Ok(())
     }
 
     /// Partially update watchlist for a specific account: change watchlist name, add to the beginning/end of a watchlist, update or delete items in a watchlist. This method does not verify that the symbol or asset type are valid
@@ -1223,7 +1197,7 @@ impl AsyncTDAClient {
     /// Parameters
     /// - acct_id: Account number
     /// - watchlist_id: Watchlist number
-    /// - watchlist_spec: Watchlist body
+    /// - watchlist_spec: Watchlist body. Build with `WatchlistPatchBuilder`
     ///
     /// Official Documentation: https://developer.tdameritrade.com/watchlist/apis/patch/accounts/%7BaccountId%7D/watchlists/%7BwatchlistId%7D-0
     pub async fn update_watchlist(
@@ -1232,13 +1206,8 @@ impl AsyncTDAClient {
         watchlist_id: i64,
         watchlist_spec: &str,
     ) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Convert Watchlist Spec To String
         let body: String = watchlist_spec.into();
@@ -1247,7 +1216,7 @@ impl AsyncTDAClient {
         let url: String = format!("accounts/{}/watchlists/{}", acct_id, watchlist_id);
 
         // Update Watchlist
-        requests_async::patch(&self.reqwest_client, access_token, body, url).await
+        requests_async::patch(&self.reqwest_client, &self.config, access_token, body, url).await
     }
 
     /// Delete watchlist for a specific account
@@ -1262,18 +1231,19 @@ impl AsyncTDAClient {
         acct_id: i64,
         watchlist_id: i64,
     ) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().await.check_token_validity().await?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().await.get_access_token();
+        let access_token: Secret = self.get_access_token().await?;
 
         // Format URL
         let url: String = format!("accounts/{}/watchlists/{}", acct_id, watchlist_id);
 
         // Delete Watchlist
-        requests_async::delete(&self.reqwest_client, access_token, url).await
+        requests_async::delete(&self.reqwest_client, &self.config, access_token, url).await
     }
 }
+
+// AsyncTDAClient Must Be Shareable Across Threads
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<AsyncTDAClient>();
+};
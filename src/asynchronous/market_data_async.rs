@@ -0,0 +1,299 @@
+use crate::asynchronous::requests_async;
+use crate::builders::{OptionChain, PriceHistory};
+use crate::client_builder::TDAClientBuilder;
+use crate::config::ClientConfig;
+use crate::error::TDAClientError;
+use crate::market_data::MarketDataRequest;
+use reqwest::Client;
+
+/// Client for delayed market data that only needs a client id (consumer key), without a token
+///
+/// Only the read-only market data endpoints (instruments, market hours, movers, option chains, price history, and quotes) are available. Use `AsyncTDAClient` for accounts and trading
+#[derive(Debug, Clone)]
+pub struct AsyncMarketDataClient {
+    reqwest_client: Client,
+    config: ClientConfig,
+    client_id: String,
+}
+
+impl AsyncMarketDataClient {
+    /// Create New Asynchronous Market Data Client
+    ///
+    /// Use `TDAClientBuilder::market_data` to configure URLs, timeouts, user agent, proxy, rate limiting, retries, or the reqwest client
+    pub fn new(client_id: String) -> Result<AsyncMarketDataClient, TDAClientError> {
+        TDAClientBuilder::market_data(client_id).build_market_data_async()
+    }
+
+    // Create AsyncMarketDataClient From Configured Parts
+    pub(crate) fn from_parts(
+        reqwest_client: Client,
+        client_id: String,
+        config: ClientConfig,
+    ) -> Self {
+        AsyncMarketDataClient {
+            reqwest_client,
+            config,
+            client_id,
+        }
+    }
+
+    //// Instruments ////
+
+    /// Search or retrieve instrument data, including fundamental data
+    ///
+    /// Parameters
+    /// - symbol: Value to pass to the search. See projection description for more information
+    /// - projection: The type of request
+    ///     - symbol-search: Retrieve instrument data of a specific symbol or cusip
+    ///     - symbol-regex: Retrieve instrument data for all symbols matching regex. Example: symbol=`XYZ.*` will return all symbols beginning with XYZ
+    ///     - desc-search: Retrieve instrument data for instruments whose description contains the word supplied. Example: symbol=`FakeCompany` will return all instruments with FakeCompany in the description
+    ///     - desc-regex: Search description with full regex support. Example: symbol=`XYZ.[A-C]` returns all instruments whose descriptions contain a word beginning with XYZ followed by a character A through C
+    ///     - fundamental: Returns fundamental data for a single instrument specified by exact symbol.
+    ///
+    /// Official Documentation: https://developer.tdameritrade.com/instruments/apis/get/instruments
+    pub async fn search_instruments(
+        &self,
+        symbol: &str,
+        projection: &str,
+    ) -> Result<String, TDAClientError> {
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::search_instruments(symbol, projection);
+
+        // Return String Response
+        requests_async::get_with_api_key(
+            &self.reqwest_client,
+            &self.config,
+            &self.client_id,
+            request.params,
+            request.url,
+        )
+        .await
+    }
+
+    /// Get an instrument by CUSIP
+    ///
+    /// Parameters
+    /// - cusip: CUSIP string
+    ///
+    /// Official Documentation: https://developer.tdameritrade.com/instruments/apis/get/instruments/%7Bcusip%7D
+    pub async fn get_instrument(&self, cusip: &str) -> Result<String, TDAClientError> {
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::get_instrument(cusip);
+
+        // Return String Response
+        requests_async::get_with_api_key(
+            &self.reqwest_client,
+            &self.config,
+            &self.client_id,
+            request.params,
+            request.url,
+        )
+        .await
+    }
+
+    //// Market Hours ////
+
+    /// Retrieve market hours for specified markets
+    ///
+    /// Parameters
+    /// - markets: The markets for which you're requesting market hours. Valid markets are `EQUITY`, `OPTION`, `FUTURE`, `BOND`, or `FOREX`
+    /// - date: The date for which market hours information is requested. Valid ISO-8601 formats are `yyyy-MM-dd` or `yyyy-MM-dd'T'HH:mm:ssz`
+    ///
+    /// Official Documentation: https://developer.tdameritrade.com/market-hours/apis/get/marketdata/hours
+    pub async fn get_hours_for_multiple_markets(
+        &self,
+        markets: &Vec<&str>,
+        date: &str,
+    ) -> Result<String, TDAClientError> {
+        // Create Request
+        let request: MarketDataRequest =
+            MarketDataRequest::get_hours_for_multiple_markets(markets, date);
+
+        // Return String Response
+        requests_async::get_with_api_key(
+            &self.reqwest_client,
+            &self.config,
+            &self.client_id,
+            request.params,
+            request.url,
+        )
+        .await
+    }
+
+    /// Retrieve market hours for specified single market
+    ///
+    /// Parameters
+    /// - markets: The markets for which you're requesting market hours. Valid markets are `EQUITY`, `OPTION`, `FUTURE`, `BOND`, or `FOREX`
+    /// - date: The date for which market hours information is requested. Valid ISO-8601 formats are `yyyy-MM-dd` or `yyyy-MM-dd'T'HH:mm:ssz`
+    ///
+    /// Official Documentation: https://developer.tdameritrade.com/market-hours/apis/get/marketdata/%7Bmarket%7D/hours
+    pub async fn get_hours_for_single_market(
+        &self,
+        market: &str,
+        date: &str,
+    ) -> Result<String, TDAClientError> {
+        // Create Request
+        let request: MarketDataRequest =
+            MarketDataRequest::get_hours_for_single_market(market, date);
+
+        // Return String Response
+        requests_async::get_with_api_key(
+            &self.reqwest_client,
+            &self.config,
+            &self.client_id,
+            request.params,
+            request.url,
+        )
+        .await
+    }
+
+    //// Movers ////
+
+    /// Top 10 (up or down) movers by value or percent for a particular market
+    ///
+    /// Parameters
+    /// - index: The index symbol to get movers from. Valid indexes are `$COMPX`, `$DJI`, or `$SPX.X`
+    /// - direction: To return movers with the specified directions. Valid directions are `up` or `down`
+    /// - change: To return movers with the specified change types. Valid change types are `percent` or `value`
+    ///
+    /// Official Documentation: https://developer.tdameritrade.com/movers/apis/get/marketdata/%7Bindex%7D/movers
+    pub async fn get_movers(
+        &self,
+        index: &str,
+        direction: &str,
+        change: &str,
+    ) -> Result<String, TDAClientError> {
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::get_movers(index, direction, change);
+
+        // Return String Response
+        requests_async::get_with_api_key(
+            &self.reqwest_client,
+            &self.config,
+            &self.client_id,
+            request.params,
+            request.url,
+        )
+        .await
+    }
+
+    //// Option Chains ////
+
+    /// Get option chain for an optionable symbol
+    ///
+    /// Parameters
+    /// - option_params: `OptionChain` object
+    ///     - symbol: Enter one symbol (case-sensitive)
+    ///     - contract_type: Type of contracts to return in the chain. Valid contract types are `CALL`, `PUT`, or `ALL`. Default is `ALL` (Optional)
+    ///     - strike_count: The number of strikes to return above and below the at-the-money price (Optional)
+    ///     - include_quotes: Include quotes for options in the option chain. Default is false (Optional)
+    ///     - strategy: Passing a value returns a strategy chain. Valid strategy values are `SINGLE`, `ANALYTICAL` (allows use of the volatility, underlyingPrice, interestRate, and daysToExpiration params to calculate theoretical values), `COVERED`, `VERTICAL`, `CALENDAR`, `STRANGLE`, `STRADDLE`, `BUTTERFLY`, `CONDOR`, `DIAGONAL`, `COLLAR`, or `ROLL`. Default is `SINGLE` (Optional)
+    ///     - interval: Strike interval for spread strategy chains (Optional)
+    ///     - strike: Provide a strike price to return options only at that strike price (Optional)
+    ///     - range: Returns options for the given range. Valid ranges are `ITM` (In the money), `NTM` (Near the money), `OTM` (Out of the money), `SAK` (Strikes above market), `SBK` (Strikes below market), `SNK` (Strikes near market), or `ALL` (All strikes). Default is `ALL` (Optional)
+    ///     - from_date: Only return expirations after this date. For strategies, expiration refers to the nearest term expiration in the strategy. Valid ISO-8601 formats are `yyyy-MM-dd` or `yyyy-MM-dd'T'HH:mm:ssz` (Optional)
+    ///     - to_date: Only return expirations before this date. For strategies, expiration refers to the nearest term expiration in the strategy. Valid ISO-8601 formats are `yyyy-MM-dd` or `yyyy-MM-dd'T'HH:mm:ssz` (Optional)
+    ///     - volatility: Volatility to use in calculations. Applies only to `ANALYTICAL` strategy chains (Optional)
+    ///     - underlying_price: Underlying price to use in calculations. Applies only to `ANALYTICAL` strategy chains (Optional)
+    ///     - interest_rate: Interest rate to use in calculations. Applies only to `ANALYTICAL` strategy chains (Optional)
+    ///     - days_to_expiration: Days to expiration to use in calculations. Applies only to `ANALYTICAL` strategy chains (Optional)
+    ///     - expiration_month: Return only options expiring in the specified month. Month is given in the three character format. Example: `JAN`. Default is `ALL` (Optional)
+    ///     - option_type: Type of contracts to return. Valid option types are `S` (Standard contracts), `NS` (Non-standard contracts), or `ALL` (All contracts). Default is `ALL` (Optional)
+    ///
+    /// Official Documentation: https://developer.tdameritrade.com/option-chains/apis/get/marketdata/chains
+    pub async fn get_option_chain(
+        &self,
+        option_params: &OptionChain,
+    ) -> Result<String, TDAClientError> {
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::get_option_chain(option_params);
+
+        // Return String Response
+        requests_async::get_with_api_key(
+            &self.reqwest_client,
+            &self.config,
+            &self.client_id,
+            request.params,
+            request.url,
+        )
+        .await
+    }
+
+    //// Price History ////
+
+    /// Get price history for a symbol
+    ///
+    /// Parameters
+    /// - history_params: `PriceHistory` object
+    ///     - symbol: Enter one symbol (case-sensitive)
+    ///     - period_type: The type of period to show. Valid period types are `day`, `month`, `year`, or `ytd` (year to date)
+    ///     - period: The number of periods to show. Valid periods for day are `1`, `2`, `3`, `4`, `5`, or `10`. Valid periods for month are `1`, `2`, `3`, or `6`. Valid periods for year are `1`, `2`, `3`, `5`, `10`, `15`, or `20`. Valid periods for ytd are `1` (Optional)
+    ///     - frequency_type: The type of frequency with which a new candle is formed. Valid frequency types for day are `minute`. Valid frequency types for month are `daily` or `weekly`. Valid frequency types for year are `daily`, `weekly`, or `monthly`. Valid frequency types for ytd are `daily` or `weekly`
+    ///     - frequency: The number of the frequency_type to be included in each candle. Valid frequencies for minute are `1`, `5`, `10`, `15`, or `30`. Valid frequencies for daily are `1`. Valid frequencies for weekly are `1`. Valid frequencies for monthly are `1`
+    ///     - start_date: Start date as milliseconds since epoch. If start_date and end_date are provided, period should not be provided (Optional)
+    ///     - end_date: End date as milliseconds since epoch. If start_date and end_date are provided, period should not be provided (Optional)
+    ///     - need_extended_hours_data: Returns extended hours data. Default is true (Optional)
+    ///
+    /// Official Documentation: https://developer.tdameritrade.com/price-history/apis/get/marketdata/%7Bsymbol%7D/pricehistory
+    pub async fn get_price_history(
+        &self,
+        history_params: &PriceHistory,
+    ) -> Result<String, TDAClientError> {
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::get_price_history(history_params);
+
+        // Return String Response
+        requests_async::get_with_api_key(
+            &self.reqwest_client,
+            &self.config,
+            &self.client_id,
+            request.params,
+            request.url,
+        )
+        .await
+    }
+
+    //// Quotes ////
+
+    /// Get quote for a symbol
+    ///
+    /// Parameters
+    /// - symbol: Enter one symbol (case-sensitive)
+    ///
+    /// Official Documentation: https://developer.tdameritrade.com/quotes/apis/get/marketdata/%7Bsymbol%7D/quotes
+    pub async fn get_quote(&self, symbol: &str) -> Result<String, TDAClientError> {
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::get_quote(symbol);
+
+        // Return String Response
+        requests_async::get_with_api_key(
+            &self.reqwest_client,
+            &self.config,
+            &self.client_id,
+            request.params,
+            request.url,
+        )
+        .await
+    }
+
+    /// Get quote for one or more symbols
+    ///
+    /// Parameters
+    /// - symbols: Enter one or more symbols in a vector (case-sensitive)
+    ///
+    /// Official Documentation: https://developer.tdameritrade.com/quotes/apis/get/marketdata/quotes
+    pub async fn get_quotes(&self, symbols: &Vec<&str>) -> Result<String, TDAClientError> {
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::get_quotes(symbols);
+
+        // Return String Response
+        requests_async::get_with_api_key(
+            &self.reqwest_client,
+            &self.config,
+            &self.client_id,
+            request.params,
+            request.url,
+        )
+        .await
+    }
+}
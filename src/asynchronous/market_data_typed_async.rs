@@ -0,0 +1,107 @@
+use crate::builders::{OptionChain as OptionChainParams, PriceHistory as PriceHistoryParams};
+use crate::error::TDAClientError;
+use crate::output::{
+    instruments::{InstrumentData, Instruments},
+    market_hours::MarketHours,
+    movers::Mover,
+    option_chains::OptionChain,
+    price_history::PriceHistory,
+    quotes::Quotes,
+};
+use crate::response;
+use crate::AsyncMarketDataClient;
+
+// Typed Counterparts Of The String Endpoints, Deserialized Into The Output Structs
+impl AsyncMarketDataClient {
+    //// Instruments ////
+
+    /// Same as [`search_instruments`](AsyncMarketDataClient::search_instruments), deserialized into `Instruments`
+    pub async fn search_instruments_typed(
+        &self,
+        symbol: &str,
+        projection: &str,
+    ) -> Result<Instruments, TDAClientError> {
+        let res: String = self.search_instruments(symbol, projection).await?;
+        response::parse_json("GET instruments", &res)
+    }
+
+    /// Same as [`get_instrument`](AsyncMarketDataClient::get_instrument), deserialized into `Vec<InstrumentData>`
+    pub async fn get_instrument_typed(
+        &self,
+        cusip: &str,
+    ) -> Result<Vec<InstrumentData>, TDAClientError> {
+        let res: String = self.get_instrument(cusip).await?;
+        response::parse_json("GET instruments/{cusip}", &res)
+    }
+
+    //// Market Hours ////
+
+    /// Same as [`get_hours_for_multiple_markets`](AsyncMarketDataClient::get_hours_for_multiple_markets), deserialized into `MarketHours`
+    pub async fn get_hours_for_multiple_markets_typed(
+        &self,
+        markets: &Vec<&str>,
+        date: &str,
+    ) -> Result<MarketHours, TDAClientError> {
+        let res: String = self.get_hours_for_multiple_markets(markets, date).await?;
+        response::parse_json("GET marketdata/hours", &res)
+    }
+
+    /// Same as [`get_hours_for_single_market`](AsyncMarketDataClient::get_hours_for_single_market), deserialized into `MarketHours`
+    pub async fn get_hours_for_single_market_typed(
+        &self,
+        market: &str,
+        date: &str,
+    ) -> Result<MarketHours, TDAClientError> {
+        let res: String = self.get_hours_for_single_market(market, date).await?;
+        response::parse_json("GET marketdata/{market}/hours", &res)
+    }
+
+    //// Movers ////
+
+    /// Same as [`get_movers`](AsyncMarketDataClient::get_movers), deserialized into `Vec<Mover>`
+    pub async fn get_movers_typed(
+        &self,
+        index: &str,
+        direction: &str,
+        change: &str,
+    ) -> Result<Vec<Mover>, TDAClientError> {
+        let res: String = self.get_movers(index, direction, change).await?;
+        response::parse_json("GET marketdata/{index}/movers", &res)
+    }
+
+    //// Option Chains ////
+
+    /// Same as [`get_option_chain`](AsyncMarketDataClient::get_option_chain), deserialized into `OptionChain`
+    pub async fn get_option_chain_typed(
+        &self,
+        option_params: &OptionChainParams,
+    ) -> Result<OptionChain, TDAClientError> {
+        let res: String = self.get_option_chain(option_params).await?;
+        response::parse_json("GET marketdata/chains", &res)
+    }
+
+    //// Price History ////
+
+    /// Same as [`get_price_history`](AsyncMarketDataClient::get_price_history), deserialized into `PriceHistory`
+    pub async fn get_price_history_typed(
+        &self,
+        history_params: &PriceHistoryParams,
+    ) -> Result<PriceHistory, TDAClientError> {
+        let res: String = self.get_price_history(history_params).await?;
+        response::parse_json("GET marketdata/{symbol}/pricehistory", &res)
+    }
+
+    //// Quotes ////
+
+    /// Same as [`get_quote`](AsyncMarketDataClient::get_quote), deserialized into `Quotes`
+    pub async fn get_quote_typed(&self, symbol: &str) -> Result<Quotes, TDAClientError> {
+        let res: String = self.get_quote(symbol).await?;
+        response::parse_json("GET marketdata/{symbol}/quotes", &res)
+    }
+
+    /// Same as [`get_quotes`](AsyncMarketDataClient::get_quotes), deserialized into `Quotes`
+    pub async fn get_quotes_typed(&self, symbols: &Vec<&str>) -> Result<Quotes, TDAClientError> {
+        let res: String = self.get_quotes(symbols).await?;
+        response::parse_json("GET marketdata/quotes", &res)
+    }
+}
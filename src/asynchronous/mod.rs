@@ -1,3 +1,7 @@
-mod auth_async;
+pub(crate) mod auth_async;
 pub mod client_async;
+pub(crate) mod market_data_async;
+mod market_data_typed_async;
+pub(crate) mod refresher_async;
 mod requests_async;
+mod typed_async;
@@ -0,0 +1,155 @@
+use crate::asynchronous::auth_async::AsyncAuth;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{oneshot, RwLock};
+use tokio::task::JoinHandle;
+
+// Wait Before Retrying A Failed Refresh
+const RETRY_DELAY: Duration = Duration::from_secs(30);
+
+// How Long Before Requests Would Refresh A Token The Refresher Refreshes It
+const REFRESH_LEAD_TIME: Duration = Duration::from_secs(60);
+
+// Shortest Wait Between Refreshes
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Handle to a background tokio task that refreshes the access token before it expires and replaces the refresh token before it expires. It refreshes a minute before requests would, and a token installed by a request in the meantime is kept
+///
+/// Created by `AsyncTDAClient::start_token_refresher`. The task stops when `shutdown` is called or the handle is dropped, or when the token can't be refreshed without creating a new one
+#[derive(Debug)]
+pub struct AsyncTokenRefresher {
+    shutdown_sender: Option<oneshot::Sender<()>>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl AsyncTokenRefresher {
+    // Start Refresher Task
+    pub(crate) fn start(auth: Arc<RwLock<AsyncAuth>>) -> AsyncTokenRefresher {
+        let (shutdown_sender, mut shutdown_receiver) = oneshot::channel::<()>();
+
+        let task: JoinHandle<()> = tokio::spawn(async move {
+            let mut wait: Duration = auth
+                .read()
+                .await
+                .next_refresh_in()
+                .saturating_sub(REFRESH_LEAD_TIME);
+
+            loop {
+                // Sleep Until The Next Refresh Or Shutdown
+                tokio::select! {
+                    _ = tokio::time::sleep(wait.max(MIN_REFRESH_INTERVAL)) => {}
+                    _ = &mut shutdown_receiver => return,
+                }
+
+                // Refresh A Copy So Requests Keep Using The Current Token Until The New One Is Ready. A Request That Must Refresh Meanwhile Waits On The Auth's Refresh Lock, Then Reuses The New Token
+                let current_auth: AsyncAuth = auth.read().await.clone();
+                let mut refreshed_auth: AsyncAuth = current_auth.clone();
+
+                wait = match refreshed_auth.refresh_tokens_ahead(REFRESH_LEAD_TIME).await {
+                    Ok(()) => {
+                        let mut auth = auth.write().await;
+
+                        // A Request Refreshed The Shared Auth Meanwhile. Keep Its Token
+                        if auth.holds_same_tokens(&current_auth) {
+                            *auth = refreshed_auth;
+                        }
+
+                        auth.next_refresh_in().saturating_sub(REFRESH_LEAD_TIME)
+                    }
+                    // New Token Must Be Created. Reauth Callback Was Already Called
+                    Err(err) if err.requires_reauth() => return,
+                    Err(_) => RETRY_DELAY,
+                };
+            }
+        });
+
+        AsyncTokenRefresher {
+            shutdown_sender: Some(shutdown_sender),
+            task: Some(task),
+        }
+    }
+
+    /// Stop the refresher and wait for its task to finish
+    pub async fn shutdown(mut self) {
+        self.shutdown_sender.take();
+
+        if let Some(task) = self.task.take() {
+            task.await.ok();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use crate::test_util::{auth_config, oauth_server, token, NOW};
+    use crate::token_store::MemoryTokenStore;
+    use reqwest::Client;
+    use std::sync::mpsc::Receiver;
+    use std::time::Instant;
+
+    fn shared_auth(
+        token_response: &'static str,
+        clock: &ManualClock,
+    ) -> (Arc<RwLock<AsyncAuth>>, Receiver<String>) {
+        let (auth_url, requests) = oauth_server(token_response);
+        let auth: AsyncAuth = AsyncAuth::from_token(
+            Client::new(),
+            "client_id@AMER.OAUTHAP".into(),
+            "https://localhost".into(),
+            Arc::new(MemoryTokenStore::new(token("access", "refresh"))),
+            auth_config(&auth_url, clock, None),
+            token("access", "refresh"),
+        );
+
+        (Arc::new(RwLock::new(auth)), requests)
+    }
+
+    #[tokio::test]
+    async fn refreshes_before_requests_would() {
+        // Requests Would Refresh In 30 Seconds, Inside The Refresher's Lead
+        let clock = ManualClock::new(NOW + 1800 - 300 - 30);
+        let (auth, requests) = shared_auth(
+            r#"{"access_token": "new_access", "scope": "", "expires_in": 1800, "token_type": "Bearer"}"#,
+            &clock,
+        );
+        assert!(auth.read().await.is_token_valid());
+
+        let refresher: AsyncTokenRefresher = AsyncTokenRefresher::start(auth.clone());
+
+        // Wait For The New Token To Be Installed
+        let deadline: Instant = Instant::now() + Duration::from_secs(10);
+        while auth.read().await.get_access_token().expose_secret() != "new_access" {
+            assert!(Instant::now() < deadline, "Token Wasn't Installed");
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        assert!(requests.try_recv().is_ok());
+
+        // Next Refresh Is Far Away, So Shutdown Must Interrupt The Wait
+        let shutdown_start: Instant = Instant::now();
+        refresher.shutdown().await;
+
+        assert!(shutdown_start.elapsed() < Duration::from_secs(1));
+        assert!(requests.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn stops_on_shutdown_without_refreshing() {
+        let clock = ManualClock::new(NOW);
+        let (auth, requests) = shared_auth("{}", &clock);
+
+        let refresher: AsyncTokenRefresher = AsyncTokenRefresher::start(auth.clone());
+
+        let shutdown_start: Instant = Instant::now();
+        refresher.shutdown().await;
+
+        assert!(shutdown_start.elapsed() < Duration::from_secs(1));
+        assert!(requests.try_recv().is_err());
+        assert_eq!(
+            auth.read().await.get_access_token().expose_secret(),
+            "access"
+        );
+    }
+}
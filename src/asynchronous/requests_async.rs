@@ -1,109 +1,222 @@
+use crate::config::ClientConfig;
 use crate::error::TDAClientError;
-use reqwest::Client;
+use crate::response;
+use crate::secret::Secret;
+use reqwest::header::{HeaderValue, AUTHORIZATION};
+use reqwest::{Client, RequestBuilder, Response};
 use std::collections::HashMap;
 
-static BASE_URL: &str = "https://api.tdameritrade.com/v1/";
+// Return Response If Successful, Otherwise Convert To Error
+async fn check_status(res: Response) -> Result<Response, TDAClientError> {
+    // Successful Response
+    if res.status().is_success() {
+        return Ok(res);
+    }
+
+    // Get Status, Headers, And Error Body
+    let status = res.status();
+    let headers = res.headers().clone();
+    let res_text: String = res.text().await?;
+
+    Err(TDAClientError::from_status(status, &headers, &res_text))
+}
+
+// Wait Until Rate Limiter Allows Request
+async fn wait_for_rate_limit(config: &ClientConfig, is_get: bool, url: &str) {
+    let delay = config.rate_limit_delay(is_get, url);
+
+    if !delay.is_zero() {
+        tokio::time::sleep(delay).await;
+    }
+}
+
+// Send Request, Retrying Transient Failures According To The Retry Policy
+async fn send(
+    config: &ClientConfig,
+    is_get: bool,
+    url: &str,
+    request: impl Fn() -> RequestBuilder,
+) -> Result<Response, TDAClientError> {
+    let mut attempt: u32 = 0;
+
+    loop {
+        // Wait For Rate Limiter
+        wait_for_rate_limit(config, is_get, url).await;
+
+        // Send Request And Check Status
+        let err: TDAClientError = match request().send().await {
+            Ok(res) => match check_status(res).await {
+                Ok(res) => return Ok(res),
+                Err(err) => err,
+            },
+            Err(err) => err.into(),
+        };
+
+        // Retry Or Return Error
+        match config.retry_delay(is_get, attempt, &err) {
+            Some(delay) => tokio::time::sleep(delay).await,
+            None => return Err(err),
+        }
+
+        attempt += 1;
+    }
+}
 
 pub async fn delete(
     reqwest_client: &Client,
-    access_token: String,
+    config: &ClientConfig,
+    access_token: Secret,
     url: String,
 ) -> Result<(), TDAClientError> {
     // Format Url
-    let formatted_url: String = format!("{}{}", BASE_URL, url);
+    let formatted_url: String = config.url(&url);
 
-    // Put Request
-    reqwest_client
-        .delete(formatted_url)
-        .header("Authorization", format!("Bearer {}", access_token))
-        .send()
-        .await?;
+    // Authorization Header
+    let authorization: HeaderValue = access_token.bearer_header()?;
+
+    // Delete Request
+    send(config, false, &url, || {
+        reqwest_client
+            .delete(&formatted_url)
+            .header(AUTHORIZATION, authorization.clone())
+    })
+    .await?;
 
     Ok(())
 }
 
 pub async fn get(
     reqwest_client: &Client,
-    access_token: String,
+    config: &ClientConfig,
+    access_token: Secret,
     params: HashMap<String, String>,
     url: String,
 ) -> Result<String, TDAClientError> {
     // Format Url
-    let formatted_url: String = format!("{}{}", BASE_URL, url);
+    let formatted_url: String = config.url(&url);
+
+    // Authorization Header
+    let authorization: HeaderValue = access_token.bearer_header()?;
+
+    // Get Request
+    let res = send(config, true, &url, || {
+        reqwest_client
+            .get(&formatted_url)
+            .query(&params)
+            .header(AUTHORIZATION, authorization.clone())
+    })
+    .await?;
+
+    // Get Response Text
+    let res_text: String = res.text().await?;
+
+    // Return Response Text
+    Ok(res_text)
+}
+
+// Unauthenticated Get Request For Delayed Market Data. The Client Id Is Sent As The apikey Parameter
+pub async fn get_with_api_key(
+    reqwest_client: &Client,
+    config: &ClientConfig,
+    api_key: &str,
+    mut params: HashMap<String, String>,
+    url: String,
+) -> Result<String, TDAClientError> {
+    // Format Url
+    let formatted_url: String = config.url(&url);
+
+    // Add API Key
+    params.insert("apikey".into(), api_key.into());
 
     // Get Request
-    let res = reqwest_client
-        .get(formatted_url)
-        .query(&params)
-        .header("Authorization", format!("Bearer {}", access_token))
-        .send()
-        .await?
-        .text()
-        .await?;
+    let res = send(config, true, &url, || {
+        reqwest_client.get(&formatted_url).query(&params)
+    })
+    .await?;
+
+    // Get Response Text
+    let res_text: String = res.text().await?;
 
     // Return Response Text
-    Ok(res)
+    Ok(res_text)
 }
 
 pub async fn patch(
     reqwest_client: &Client,
-    access_token: String,
+    config: &ClientConfig,
+    access_token: Secret,
     body: String,
     url: String,
 ) -> Result<(), TDAClientError> {
     // Format Url
-    let formatted_url: String = format!("{}{}", BASE_URL, url);
+    let formatted_url: String = config.url(&url);
 
-    // Put Request
-    reqwest_client
-        .patch(formatted_url)
-        .header("Authorization", format!("Bearer {}", access_token))
-        .header("content-type", "application/json")
-        .body(body)
-        .send()
-        .await?;
+    // Authorization Header
+    let authorization: HeaderValue = access_token.bearer_header()?;
+
+    // Patch Request
+    send(config, false, &url, || {
+        reqwest_client
+            .patch(&formatted_url)
+            .header(AUTHORIZATION, authorization.clone())
+            .header("content-type", "application/json")
+            .body(body.clone())
+    })
+    .await?;
 
     Ok(())
 }
 
 pub async fn post(
     reqwest_client: &Client,
-    access_token: String,
+    config: &ClientConfig,
+    access_token: Secret,
     body: String,
     url: String,
-) -> Result<(), TDAClientError> {
+) -> Result<Option<String>, TDAClientError> {
     // Format Url
-    let formatted_url: String = format!("{}{}", BASE_URL, url);
+    let formatted_url: String = config.url(&url);
 
-    // Post Request
-    reqwest_client
-        .post(formatted_url)
-        .header("Authorization", format!("Bearer {}", access_token))
-        .header("content-type", "application/json")
-        .body(body)
-        .send()
-        .await?;
+    // Authorization Header
+    let authorization: HeaderValue = access_token.bearer_header()?;
 
-    Ok(())
+    // Post Request
+    let res = send(config, false, &url, || {
+        reqwest_client
+            .post(&formatted_url)
+            .header(AUTHORIZATION, authorization.clone())
+            .header("content-type", "application/json")
+            .body(body.clone())
+    })
+    .await?;
+
+    // Return Location Header
+    Ok(response::location(res.headers()))
 }
 
 pub async fn put(
     reqwest_client: &Client,
-    access_token: String,
+    config: &ClientConfig,
+    access_token: Secret,
     body: String,
     url: String,
-) -> Result<(), TDAClientError> {
+) -> Result<Option<String>, TDAClientError> {
     // Format Url
-    let formatted_url: String = format!("{}{}", BASE_URL, url);
+    let formatted_url: String = config.url(&url);
 
-    // Put Request
-    reqwest_client
-        .put(formatted_url)
-        .header("Authorization", format!("Bearer {}", access_token))
-        .header("content-type", "application/json")
-        .body(body)
-        .send()
-        .await?;
+    // Authorization Header
+    let authorization: HeaderValue = access_token.bearer_header()?;
 
-    Ok(())
+    // Put Request
+    let res = send(config, false, &url, || {
+        reqwest_client
+            .put(&formatted_url)
+            .header(AUTHORIZATION, authorization.clone())
+            .header("content-type", "application/json")
+            .body(body.clone())
+    })
+    .await?;
+
+    // Return Location Header
+    Ok(response::location(res.headers()))
 }
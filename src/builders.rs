@@ -1,6 +1,15 @@
+use crate::order_enums::{
+    AssetType, ComplexOrderStrategyType, Duration, Instruction, OrderStrategyType, OrderType,
+    PriceLinkBasis, PriceLinkType, RequestedDestination, Session, SpecialInstruction,
+    StopPriceLinkBasis, StopPriceLinkType, StopType,
+};
+use crate::output::trading::{
+    ChildOrderStrategy, ComplexOrder, Order, OrderLegCollection, SimpleOrder, SimpleSavedOrder,
+};
 use crate::TDAClientError;
 use derive_builder::Builder;
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Debug, Builder, Default, Clone)]
 #[builder(setter(into))]
@@ -60,54 +69,58 @@ pub struct PriceHistory {
 /// Builder to streamline creation of TD Ameritrade orders
 ///
 /// Parameters
-/// - session: The market session when order should be executed. Valid sessions are `NORMAL`, `AM`, `PM`, or `SEAMLESS`
-/// - duration: Length of time order will be active. Valid durations are `DAY`, `GOOD_TILL_CANCEL`, or `FILL_OR_KILL`
-/// - order_type: Type of order. Valid order types are `MARKET`, `LIMIT`, `STOP`, `STOP_LIMIT`, `TRAILING_STOP`, `MARKET_ON_CLOSE`, `EXERCISE`, `TRAILING_STOP_LIMIT`, `NET_DEBIT`, `NET_CREDIT`, or `NET_ZERO`
-/// - complex_order_strategy_type: Strategy type for complex orders. Valid complex strategy types are `NONE`, `COVERED`, `VERTICAL`, `BACK_RATIO`, `CALENDAR`, `DIAGONAL`, `STRADDLE`, `STRANGLE`, `COLLAR_SYNTHETIC`, `BUTTERFLY`, `CONDOR`, `IRON_CONDOR`, `VERTICAL_ROLL`, `COLLAR_WITH_STOCK`, `DOUBLE_DIAGONAL`, `UNBALANCED_BUTTERFLY`, `UNBALANCED_CONDOR`, `UNBALANCED_IRON_CONDOR`, `UNBALANCED_VERTICAL_ROLL`, or `CUSTOM`
+/// - session: The market session when order should be executed. Accepts `Session`
+/// - duration: Length of time order will be active. Accepts `Duration`
+/// - order_type: Type of order. Accepts `OrderType`
+/// - complex_order_strategy_type: Strategy type for complex orders. Accepts `ComplexOrderStrategyType`
 /// - quantity: Number of shares for the order. Accepts `f64`
-/// - requested_destination: Preferred order destination. Valid destinations are `INET`, `ECN_ARCA`, `CBOE`, `AMEX`, `PHLX`, `ISE`, `BOX`, `NYSE`, `NASDAQ`, `BATS`, `C2`, or `AUTO`
+/// - requested_destination: Preferred order destination. Accepts `RequestedDestination`
 /// - stop_price: The stop price. Accepts `f64`
-/// - stop_price_link_basis: The stop price link basis. Valid stop price link baseis are `MANUAL`, `BASE`, `TRIGGER`, `LAST`, `BID`, `ASK`, `ASK_BID`, `MARK`, or `AVERAGE`
-/// - stop_price_link_type: The stop price link type. Valid stop price link types are `VALUE`, `PERCENT`, or `TICK`
+/// - stop_price_link_basis: The stop price link basis. Accepts `StopPriceLinkBasis`
+/// - stop_price_link_type: The stop price link type. Accepts `StopPriceLinkType`
 /// - stop_price_offset: The stop price offset. Accepts `f64`
-/// - stop_type: The stop type. Valid stop types are `STANDARD`, `BID`, `ASK`, `LAST`, or `MARK`
-/// - price_link_basis: The price link basis. Valid price link baseis are `MANUAL`, `BASE`, `TRIGGER`, `LAST`, `BID`, `ASK`, `ASK_BID`, `MARK`, or `AVERAGE`
-/// - price_link_type: The price link type. Valid price link types are `VALUE`, `PERCENT`, or `TICK`
+/// - stop_type: The stop type. Accepts `StopType`
+/// - price_link_basis: The price link basis. Accepts `PriceLinkBasis`
+/// - price_link_type: The price link type. Accepts `PriceLinkType`
 /// - price: Order price. Accepts `f64`
 /// - order_leg_collection: Order Leg Collections
 /// - activation_price: The activation price. Accepts `f64`
-/// - special_instruction: Special instruction for order. Valid instructions are `ALL_OR_NONE`, `DO_NOT_REDUCE`, or `ALL_OR_NONE_DO_NOT_REDUCE`
-/// - order_strategy_type: Strategy type for composite orders. Valid strategy types are `SINGLE`, `OCO`, or `TRIGGER`
+/// - special_instruction: Special instruction for order. Accepts `SpecialInstruction`
+/// - order_strategy_type: Strategy type for composite orders. Accepts `OrderStrategyType`
 /// - child_order_strategies: Order strategies for composite orders. Accepts `OrderBuilder`
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
+///
+/// The enums are in `order_enums` and serialize to the strings TD Ameritrade expects
+///
+/// Fetched orders convert into an `OrderBuilder` with `OrderBuilder::try_from(&order)`, and order JSON (including the JSON returned by `get_order`) deserializes into one, so a working order can be changed and passed to `replace_order`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct OrderBuilder {
     #[serde(skip_serializing_if = "Option::is_none")]
-    session: Option<String>,
+    session: Option<Session>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    duration: Option<String>,
+    duration: Option<Duration>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    order_type: Option<String>,
+    order_type: Option<OrderType>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    complex_order_strategy_type: Option<String>,
+    complex_order_strategy_type: Option<ComplexOrderStrategyType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     quantity: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    requested_destination: Option<String>,
+    requested_destination: Option<RequestedDestination>,
     #[serde(skip_serializing_if = "Option::is_none")]
     stop_price: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    stop_price_link_basis: Option<String>,
+    stop_price_link_basis: Option<StopPriceLinkBasis>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    stop_price_link_type: Option<String>,
+    stop_price_link_type: Option<StopPriceLinkType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     stop_price_offset: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    stop_type: Option<String>,
+    stop_type: Option<StopType>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    price_link_basis: Option<String>,
+    price_link_basis: Option<PriceLinkBasis>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    price_link_type: Option<String>,
+    price_link_type: Option<PriceLinkType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     price: Option<f64>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -115,27 +128,33 @@ pub struct OrderBuilder {
     #[serde(skip_serializing_if = "Option::is_none")]
     activation_price: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    special_instruction: Option<String>,
+    special_instruction: Option<SpecialInstruction>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    order_strategy_type: Option<String>,
+    order_strategy_type: Option<OrderStrategyType>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     child_order_strategies: Vec<OrderBuilder>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderLeg {
-    instruction: String,
+    instruction: Instruction,
     instrument: Instrument,
     quantity: f64,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Instrument {
-    asset_type: String,
+    asset_type: AssetType,
     symbol: String,
 }
 
+impl Default for OrderBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl OrderBuilder {
     pub fn new() -> OrderBuilder {
         OrderBuilder {
@@ -162,8 +181,8 @@ impl OrderBuilder {
     }
 
     // Session
-    pub fn set_session(mut self, session: &str) -> OrderBuilder {
-        self.session = Some(session.to_string());
+    pub fn set_session(mut self, session: Session) -> OrderBuilder {
+        self.session = Some(session);
         self
     }
 
@@ -173,8 +192,8 @@ impl OrderBuilder {
     }
 
     // Duration
-    pub fn set_duration(mut self, duration: &str) -> OrderBuilder {
-        self.duration = Some(duration.to_string());
+    pub fn set_duration(mut self, duration: Duration) -> OrderBuilder {
+        self.duration = Some(duration);
         self
     }
 
@@ -184,8 +203,8 @@ impl OrderBuilder {
     }
 
     // Order Type
-    pub fn set_order_type(mut self, order_type: &str) -> OrderBuilder {
-        self.order_type = Some(order_type.to_string());
+    pub fn set_order_type(mut self, order_type: OrderType) -> OrderBuilder {
+        self.order_type = Some(order_type);
         self
     }
 
@@ -197,9 +216,9 @@ impl OrderBuilder {
     // Complex Order Strategy Type
     pub fn set_complex_order_strategy_type(
         mut self,
-        complex_order_strategy_type: &str,
+        complex_order_strategy_type: ComplexOrderStrategyType,
     ) -> OrderBuilder {
-        self.complex_order_strategy_type = Some(complex_order_strategy_type.to_string());
+        self.complex_order_strategy_type = Some(complex_order_strategy_type);
         self
     }
 
@@ -220,8 +239,11 @@ impl OrderBuilder {
     }
 
     // Requested Destination
-    pub fn set_requested_destination(mut self, requested_destination: &str) -> OrderBuilder {
-        self.requested_destination = Some(requested_destination.to_string());
+    pub fn set_requested_destination(
+        mut self,
+        requested_destination: RequestedDestination,
+    ) -> OrderBuilder {
+        self.requested_destination = Some(requested_destination);
         self
     }
 
@@ -242,8 +264,11 @@ impl OrderBuilder {
     }
 
     // Stop Price Link Basis
-    pub fn set_stop_price_link_basis(mut self, stop_price_link_basis: &str) -> OrderBuilder {
-        self.stop_price_link_basis = Some(stop_price_link_basis.to_string());
+    pub fn set_stop_price_link_basis(
+        mut self,
+        stop_price_link_basis: StopPriceLinkBasis,
+    ) -> OrderBuilder {
+        self.stop_price_link_basis = Some(stop_price_link_basis);
         self
     }
 
@@ -253,8 +278,11 @@ impl OrderBuilder {
     }
 
     // Stop Price Link Type
-    pub fn set_stop_price_link_type(mut self, stop_price_link_type: &str) -> OrderBuilder {
-        self.stop_price_link_type = Some(stop_price_link_type.to_string());
+    pub fn set_stop_price_link_type(
+        mut self,
+        stop_price_link_type: StopPriceLinkType,
+    ) -> OrderBuilder {
+        self.stop_price_link_type = Some(stop_price_link_type);
         self
     }
 
@@ -275,8 +303,8 @@ impl OrderBuilder {
     }
 
     // Stop Type
-    pub fn set_stop_type(mut self, stop_type: &str) -> OrderBuilder {
-        self.stop_type = Some(stop_type.to_string());
+    pub fn set_stop_type(mut self, stop_type: StopType) -> OrderBuilder {
+        self.stop_type = Some(stop_type);
         self
     }
 
@@ -286,8 +314,8 @@ impl OrderBuilder {
     }
 
     // Price Link Basis
-    pub fn set_price_link_basis(mut self, price_link_basis: &str) -> OrderBuilder {
-        self.price_link_basis = Some(price_link_basis.to_string());
+    pub fn set_price_link_basis(mut self, price_link_basis: PriceLinkBasis) -> OrderBuilder {
+        self.price_link_basis = Some(price_link_basis);
         self
     }
 
@@ -297,8 +325,8 @@ impl OrderBuilder {
     }
 
     // Price Link Type
-    pub fn set_price_link_type(mut self, price_link_type: &str) -> OrderBuilder {
-        self.price_link_type = Some(price_link_type.to_string());
+    pub fn set_price_link_type(mut self, price_link_type: PriceLinkType) -> OrderBuilder {
+        self.price_link_type = Some(price_link_type);
         self
     }
 
@@ -330,8 +358,11 @@ impl OrderBuilder {
     }
 
     // Special Instruction
-    pub fn set_special_instruction(mut self, special_instruction: &str) -> OrderBuilder {
-        self.special_instruction = Some(special_instruction.to_string());
+    pub fn set_special_instruction(
+        mut self,
+        special_instruction: SpecialInstruction,
+    ) -> OrderBuilder {
+        self.special_instruction = Some(special_instruction);
         self
     }
 
@@ -341,8 +372,11 @@ impl OrderBuilder {
     }
 
     // Order Strategy Type
-    pub fn set_order_strategy_type(mut self, order_strategy_type: &str) -> OrderBuilder {
-        self.order_strategy_type = Some(order_strategy_type.to_string());
+    pub fn set_order_strategy_type(
+        mut self,
+        order_strategy_type: OrderStrategyType,
+    ) -> OrderBuilder {
+        self.order_strategy_type = Some(order_strategy_type);
         self
     }
 
@@ -365,21 +399,21 @@ impl OrderBuilder {
     /// Add Order Leg
     ///
     /// Parameters
-    /// - instruction: Type of trade. Valid instructions are `BUY`, `SELL`, `BUY_TO_COVER`, `SELL_SHORT`, `BUY_TO_OPEN`, `BUY_TO_CLOSE`, `SELL_TO_OPEN`, `SELL_TO_CLOSE` or `EXCHANGE`
-    /// - instrument_asset_type: Type of asset traded. Valid asset types are `EQUITY`, `OPTION`, `INDEX`, `MUTUAL_FUND`, `CASH_EQUIVALENT`, `FIXED_INCOME`, or `CURRENCY`
-    /// - instrument_symbol: Symbol traded. Accepts `&str`
+    /// - instruction: Type of trade. Accepts `Instruction`
+    /// - instrument_asset_type: Type of asset traded. Accepts `AssetType`
+    /// - instrument_symbol: Symbol traded. Accepts `&str`, `String`, or `OptionSymbol`
     /// - quantity: Amount of shares traded. Accepts `f64`
     pub fn add_order_leg(
         mut self,
-        instruction: &str,
-        instrument_asset_type: &str,
-        instrument_symbol: &str,
+        instruction: Instruction,
+        instrument_asset_type: AssetType,
+        instrument_symbol: impl ToString,
         quantity: f64,
     ) -> OrderBuilder {
         self.order_leg_collection.push(OrderLeg {
-            instruction: instruction.to_string(),
+            instruction,
             instrument: Instrument {
-                asset_type: instrument_asset_type.to_string(),
+                asset_type: instrument_asset_type,
                 symbol: instrument_symbol.to_string(),
             },
             quantity,
@@ -392,30 +426,629 @@ impl OrderBuilder {
         self
     }
 
-    // Build OrderBuilder
+    /// Build order JSON
+    ///
+    /// Returns `TDAClientError::InvalidOrder` listing every problem found in the order and its child order strategies, such as a `LIMIT` order without a price or a quantity that is zero, negative, or NaN
     pub fn build(self) -> Result<String, TDAClientError> {
-        let built_order = OrderBuilder {
-            session: self.session,
-            duration: self.duration,
-            order_type: self.order_type,
-            complex_order_strategy_type: self.complex_order_strategy_type,
-            quantity: self.quantity,
-            requested_destination: self.requested_destination,
-            stop_price: self.stop_price,
-            stop_price_link_basis: self.stop_price_link_basis,
-            stop_price_link_type: self.stop_price_link_type,
-            stop_price_offset: self.stop_price_offset,
-            stop_type: self.stop_type,
-            price_link_basis: self.price_link_basis,
-            price_link_type: self.price_link_type,
-            price: self.price,
-            order_leg_collection: self.order_leg_collection,
-            activation_price: self.activation_price,
-            special_instruction: self.special_instruction,
-            order_strategy_type: self.order_strategy_type,
-            child_order_strategies: self.child_order_strategies,
+        // Validate Order
+        let mut violations: Vec<String> = Vec::new();
+        self.validate("Order", &mut violations);
+
+        if !violations.is_empty() {
+            return Err(TDAClientError::InvalidOrder(violations));
+        }
+
+        Ok(serde_json::to_string_pretty(&self)?)
+    }
+
+    // Add Every Rule The Order (And Its Child Order Strategies) Breaks To Violations
+    fn validate(&self, path: &str, violations: &mut Vec<String>) {
+        let mut violation = |message: String| violations.push(format!("{}: {}", path, message));
+
+        match self.order_strategy_type {
+            // OCO Orders Only Hold The Orders That Cancel Each Other
+            Some(OrderStrategyType::Oco) => {
+                if self.child_order_strategies.len() != 2 {
+                    violation(format!(
+                        "OCO Order Needs Exactly 2 Child Order Strategies, Found {}",
+                        self.child_order_strategies.len()
+                    ));
+                }
+
+                if !self.order_leg_collection.is_empty() {
+                    violation("OCO Order Can't Have Order Legs".into());
+                }
+            }
+            Some(order_strategy_type) => {
+                if order_strategy_type == OrderStrategyType::Trigger
+                    && self.child_order_strategies.is_empty()
+                {
+                    violation("TRIGGER Order Needs A Child Order Strategy".into());
+                }
+
+                if self.order_leg_collection.is_empty() {
+                    violation("Missing Order Legs".into());
+                }
+
+                if self.session.is_none() {
+                    violation("Missing Session".into());
+                }
+
+                if self.duration.is_none() {
+                    violation("Missing Duration".into());
+                }
+
+                match self.order_type {
+                    Some(order_type) => self.validate_prices(order_type, &mut violation),
+                    None => violation("Missing Order Type".into()),
+                }
+            }
+            None => violation("Missing Order Strategy Type".into()),
+        }
+
+        // Quantities
+        if let Some(quantity) = self.quantity {
+            validate_number("Quantity", quantity, true, &mut violation);
+        }
+
+        for (index, order_leg) in self.order_leg_collection.iter().enumerate() {
+            validate_number(
+                &format!(
+                    "Order Leg {} ({}) Quantity",
+                    index, order_leg.instrument.symbol
+                ),
+                order_leg.quantity,
+                true,
+                &mut violation,
+            );
+        }
+
+        // Prices. A Linked Price Is An Offset, Which Only Needs To Be A Number
+        if let Some(price) = self.price {
+            validate_number(
+                "Price",
+                price,
+                self.price_link_basis.is_none(),
+                &mut violation,
+            );
+        }
+
+        if let Some(stop_price) = self.stop_price {
+            validate_number("Stop Price", stop_price, true, &mut violation);
+        }
+
+        if let Some(stop_price_offset) = self.stop_price_offset {
+            validate_number(
+                "Stop Price Offset",
+                stop_price_offset,
+                false,
+                &mut violation,
+            );
+        }
+
+        if let Some(activation_price) = self.activation_price {
+            validate_number("Activation Price", activation_price, true, &mut violation);
+        }
+
+        // Child Order Strategies
+        for (index, child_order_strategy) in self.child_order_strategies.iter().enumerate() {
+            child_order_strategy.validate(
+                &format!("{} > Child Order Strategy {}", path, index),
+                violations,
+            );
+        }
+    }
+
+    // Check That The Order Type Has The Prices It Needs
+    fn validate_prices(&self, order_type: OrderType, violation: &mut impl FnMut(String)) {
+        let name: &str = match order_type {
+            OrderType::Limit => "LIMIT",
+            OrderType::StopLimit => "STOP_LIMIT",
+            OrderType::Stop => "STOP",
+            OrderType::TrailingStop => "TRAILING_STOP",
+            OrderType::TrailingStopLimit => "TRAILING_STOP_LIMIT",
+            OrderType::NetDebit => "NET_DEBIT",
+            OrderType::NetCredit => "NET_CREDIT",
+            _ => return,
         };
 
-        Ok(serde_json::to_string_pretty(&built_order)?)
+        // Limit Price
+        if matches!(
+            order_type,
+            OrderType::Limit | OrderType::StopLimit | OrderType::NetDebit | OrderType::NetCredit
+        ) && self.price.is_none()
+        {
+            violation(format!("{} Order Is Missing Price", name));
+        }
+
+        // Stop Price
+        if matches!(order_type, OrderType::Stop | OrderType::StopLimit) && self.stop_price.is_none()
+        {
+            violation(format!("{} Order Is Missing Stop Price", name));
+        }
+
+        // Trailing Stop Offset
+        if matches!(
+            order_type,
+            OrderType::TrailingStop | OrderType::TrailingStopLimit
+        ) {
+            if self.stop_price_offset.is_none() {
+                violation(format!("{} Order Is Missing Stop Price Offset", name));
+            }
+
+            if self.stop_price_link_basis.is_none() {
+                violation(format!("{} Order Is Missing Stop Price Link Basis", name));
+            }
+
+            if self.stop_price_link_type.is_none() {
+                violation(format!("{} Order Is Missing Stop Price Link Type", name));
+            }
+        }
+    }
+}
+
+// Check That A Quantity Or Price Is A Finite Number, And Positive If Required. NaN Fails Both Checks
+fn validate_number(name: &str, value: f64, positive: bool, violation: &mut impl FnMut(String)) {
+    if !value.is_finite() {
+        violation(format!("{} Must Be A Finite Number, Found {}", name, value));
+    } else if positive && value <= 0.0 {
+        violation(format!("{} Must Be Positive, Found {}", name, value));
+    }
+}
+
+// Convert Fetched Orders Into OrderBuilder
+impl TryFrom<&Order> for OrderBuilder {
+    type Error = TDAClientError;
+
+    fn try_from(order: &Order) -> Result<Self, Self::Error> {
+        match order {
+            Order::SimpleOrder(order) => OrderBuilder::try_from(order),
+            Order::ComplexOrder(order) => OrderBuilder::try_from(order),
+            Order::SimpleSavedOrder(order) => OrderBuilder::try_from(order),
+            Order::Other(value) => Ok(serde_json::from_value(value.clone())?),
+        }
+    }
+}
+
+impl TryFrom<Order> for OrderBuilder {
+    type Error = TDAClientError;
+
+    fn try_from(order: Order) -> Result<Self, Self::Error> {
+        OrderBuilder::try_from(&order)
+    }
+}
+
+impl TryFrom<&SimpleOrder> for OrderBuilder {
+    type Error = TDAClientError;
+
+    fn try_from(order: &SimpleOrder) -> Result<Self, Self::Error> {
+        Ok(OrderBuilder {
+            session: parse_order_field(&order.session)?,
+            duration: parse_order_field(&order.duration)?,
+            order_type: parse_order_field(&order.order_type)?,
+            complex_order_strategy_type: parse_order_field(&order.complex_order_strategy_type)?,
+            quantity: Some(order.quantity),
+            requested_destination: parse_order_field(&order.requested_destination)?,
+            stop_price: order.stop_price,
+            stop_price_link_basis: parse_optional_order_field(&order.stop_price_link_basis)?,
+            stop_price_link_type: parse_optional_order_field(&order.stop_price_link_type)?,
+            stop_price_offset: order.stop_price_offset,
+            stop_type: parse_optional_order_field(&order.stop_type)?,
+            price_link_basis: parse_optional_order_field(&order.price_link_basis)?,
+            price_link_type: parse_optional_order_field(&order.price_link_type)?,
+            price: order.price,
+            activation_price: order.activation_price,
+            special_instruction: parse_optional_order_field(&order.special_instruction)?,
+            order_leg_collection: parse_order_legs(&order.order_leg_collection)?,
+            order_strategy_type: parse_order_field(&order.order_strategy_type)?,
+            child_order_strategies: order
+                .child_order_strategies
+                .iter()
+                .map(OrderBuilder::try_from)
+                .collect::<Result<Vec<OrderBuilder>, TDAClientError>>()?,
+        })
+    }
+}
+
+impl TryFrom<&ComplexOrder> for OrderBuilder {
+    type Error = TDAClientError;
+
+    fn try_from(order: &ComplexOrder) -> Result<Self, Self::Error> {
+        Ok(OrderBuilder {
+            order_strategy_type: parse_order_field(&order.order_strategy_type)?,
+            child_order_strategies: order
+                .child_order_strategies
+                .iter()
+                .map(OrderBuilder::try_from)
+                .collect::<Result<Vec<OrderBuilder>, TDAClientError>>()?,
+            ..OrderBuilder::new()
+        })
+    }
+}
+
+impl TryFrom<&SimpleSavedOrder> for OrderBuilder {
+    type Error = TDAClientError;
+
+    fn try_from(order: &SimpleSavedOrder) -> Result<Self, Self::Error> {
+        Ok(OrderBuilder {
+            session: parse_order_field(&order.session)?,
+            duration: parse_order_field(&order.duration)?,
+            order_type: parse_order_field(&order.order_type)?,
+            complex_order_strategy_type: parse_order_field(&order.complex_order_strategy_type)?,
+            quantity: order.quantity,
+            stop_price: order.stop_price,
+            stop_price_link_basis: parse_optional_order_field(&order.stop_price_link_basis)?,
+            stop_price_link_type: parse_optional_order_field(&order.stop_price_link_type)?,
+            stop_price_offset: order.stop_price_offset,
+            stop_type: parse_optional_order_field(&order.stop_type)?,
+            price_link_basis: parse_optional_order_field(&order.price_link_basis)?,
+            price_link_type: parse_optional_order_field(&order.price_link_type)?,
+            price: order.price,
+            activation_price: order.activation_price,
+            special_instruction: parse_optional_order_field(&order.special_instruction)?,
+            order_leg_collection: parse_order_legs(&order.order_leg_collection)?,
+            order_strategy_type: parse_order_field(&order.order_strategy_type)?,
+            ..OrderBuilder::new()
+        })
+    }
+}
+
+impl TryFrom<&ChildOrderStrategy> for OrderBuilder {
+    type Error = TDAClientError;
+
+    fn try_from(order: &ChildOrderStrategy) -> Result<Self, Self::Error> {
+        Ok(OrderBuilder {
+            session: parse_order_field(&order.session)?,
+            duration: parse_order_field(&order.duration)?,
+            order_type: parse_order_field(&order.order_type)?,
+            complex_order_strategy_type: parse_order_field(&order.complex_order_strategy_type)?,
+            // OCO Children Have No Quantity, So The Default 0 Means Unset
+            quantity: Some(order.quantity).filter(|quantity| *quantity != 0.0),
+            requested_destination: parse_order_field(&order.requested_destination)?,
+            stop_price: order.stop_price,
+            stop_price_link_basis: parse_optional_order_field(&order.stop_price_link_basis)?,
+            stop_price_link_type: parse_optional_order_field(&order.stop_price_link_type)?,
+            stop_price_offset: order.stop_price_offset,
+            stop_type: parse_optional_order_field(&order.stop_type)?,
+            price_link_basis: parse_optional_order_field(&order.price_link_basis)?,
+            price_link_type: parse_optional_order_field(&order.price_link_type)?,
+            price: order.price,
+            activation_price: order.activation_price,
+            special_instruction: parse_optional_order_field(&order.special_instruction)?,
+            order_leg_collection: parse_order_legs(&order.order_leg_collection)?,
+            order_strategy_type: parse_order_field(&order.order_strategy_type)?,
+            child_order_strategies: order
+                .child_order_strategies
+                .iter()
+                .map(OrderBuilder::try_from)
+                .collect::<Result<Vec<OrderBuilder>, TDAClientError>>()?,
+        })
+    }
+}
+
+// Parse API String Into Order Enum. Empty Strings Are Treated As Unset
+fn parse_order_field<T: DeserializeOwned>(value: &str) -> Result<Option<T>, TDAClientError> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(serde_json::from_value(Value::String(value.into()))?))
+}
+
+fn parse_optional_order_field<T: DeserializeOwned>(
+    value: &Option<String>,
+) -> Result<Option<T>, TDAClientError> {
+    match value {
+        Some(value) => parse_order_field(value),
+        None => Ok(None),
+    }
+}
+
+// Convert Fetched Order Legs Into OrderLeg
+fn parse_order_legs(
+    order_leg_collection: &[OrderLegCollection],
+) -> Result<Vec<OrderLeg>, TDAClientError> {
+    order_leg_collection
+        .iter()
+        .map(|order_leg| {
+            Ok(OrderLeg {
+                instruction: serde_json::from_value(Value::String(order_leg.instruction.clone()))?,
+                instrument: Instrument {
+                    asset_type: serde_json::from_value(Value::String(
+                        order_leg.instrument.asset_type.clone(),
+                    ))?,
+                    symbol: order_leg.instrument.symbol.clone(),
+                },
+                quantity: order_leg.quantity,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Filled Orders Have A closeTime, So They Parse As Order::SimpleOrder
+    const TRIGGER_ORDER: &str = r#"{
+        "session": "NORMAL",
+        "duration": "DAY",
+        "orderType": "LIMIT",
+        "complexOrderStrategyType": "NONE",
+        "quantity": 10.0,
+        "filledQuantity": 10.0,
+        "remainingQuantity": 0.0,
+        "requestedDestination": "AUTO",
+        "destinationLinkName": "NITE",
+        "price": 150.0,
+        "orderLegCollection": [
+            {
+                "orderLegType": "EQUITY",
+                "legId": 1,
+                "instrument": { "assetType": "EQUITY", "cusip": "037833100", "symbol": "AAPL" },
+                "instruction": "BUY",
+                "positionEffect": "OPENING",
+                "quantity": 10.0
+            }
+        ],
+        "orderStrategyType": "TRIGGER",
+        "orderId": 1,
+        "cancelable": false,
+        "editable": false,
+        "status": "FILLED",
+        "enteredTime": "2022-01-01T14:30:00+0000",
+        "closeTime": "2022-01-01T14:30:01+0000",
+        "tag": "API_TDAM",
+        "accountId": 123,
+        "childOrderStrategies": [
+            {
+                "orderStrategyType": "OCO",
+                "orderId": 2,
+                "cancelable": true,
+                "editable": false,
+                "status": "ACCEPTED",
+                "enteredTime": "2022-01-01T14:30:00+0000",
+                "accountId": 123,
+                "childOrderStrategies": [
+                    {
+                        "session": "NORMAL",
+                        "duration": "GOOD_TILL_CANCEL",
+                        "orderType": "LIMIT",
+                        "complexOrderStrategyType": "NONE",
+                        "quantity": 10.0,
+                        "requestedDestination": "AUTO",
+                        "price": 160.0,
+                        "orderLegCollection": [
+                            {
+                                "orderLegType": "EQUITY",
+                                "legId": 1,
+                                "instrument": { "assetType": "EQUITY", "symbol": "AAPL" },
+                                "instruction": "SELL",
+                                "quantity": 10.0
+                            }
+                        ],
+                        "orderStrategyType": "SINGLE",
+                        "orderId": 3,
+                        "status": "WORKING"
+                    },
+                    {
+                        "session": "NORMAL",
+                        "duration": "GOOD_TILL_CANCEL",
+                        "orderType": "STOP",
+                        "complexOrderStrategyType": "NONE",
+                        "quantity": 10.0,
+                        "requestedDestination": "AUTO",
+                        "stopPrice": 145.0,
+                        "stopType": "STANDARD",
+                        "orderLegCollection": [
+                            {
+                                "orderLegType": "EQUITY",
+                                "legId": 1,
+                                "instrument": { "assetType": "EQUITY", "symbol": "AAPL" },
+                                "instruction": "SELL",
+                                "quantity": 10.0
+                            }
+                        ],
+                        "orderStrategyType": "SINGLE",
+                        "orderId": 4,
+                        "status": "WORKING"
+                    }
+                ]
+            }
+        ]
+    }"#;
+
+    // The Order JSON Sent To The API, Which Only Keeps The Fields OrderBuilder Knows
+    fn order_json(order_builder: OrderBuilder) -> Value {
+        serde_json::from_str(&order_builder.build().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn converts_trigger_order_with_children() {
+        let order: Order = serde_json::from_str(TRIGGER_ORDER).unwrap();
+        assert!(matches!(order, Order::SimpleOrder(_)));
+
+        let converted = order_json(OrderBuilder::try_from(&order).unwrap());
+        let deserialized = order_json(serde_json::from_str(TRIGGER_ORDER).unwrap());
+        assert_eq!(converted, deserialized);
+
+        let oco = &converted["childOrderStrategies"][0];
+        assert_eq!(oco["orderStrategyType"], "OCO");
+        assert!(oco.get("quantity").is_none());
+
+        let stop = &oco["childOrderStrategies"][1];
+        assert_eq!(stop["orderType"], "STOP");
+        assert_eq!(stop["stopPrice"], 145.0);
+        assert_eq!(stop["stopType"], "STANDARD");
+        assert!(stop.get("price").is_none());
+    }
+
+    #[test]
+    fn converts_working_order() {
+        // Working Orders Have No closeTime, So They Parse As Order::Other
+        let mut value: Value = serde_json::from_str(TRIGGER_ORDER).unwrap();
+        value.as_object_mut().unwrap().remove("closeTime");
+        let order: Order = serde_json::from_value(value).unwrap();
+        assert!(matches!(order, Order::Other(_)));
+
+        let converted = order_json(OrderBuilder::try_from(&order).unwrap());
+        assert_eq!(
+            converted["childOrderStrategies"][0]["childOrderStrategies"][1]["stopPrice"],
+            145.0
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_order_values() {
+        let order: Order =
+            serde_json::from_str(&TRIGGER_ORDER.replacen("\"NORMAL\"", "\"NIGHT\"", 1)).unwrap();
+
+        assert!(OrderBuilder::try_from(&order).is_err());
+    }
+
+    fn limit_order() -> OrderBuilder {
+        OrderBuilder::new()
+            .set_order_strategy_type(OrderStrategyType::Single)
+            .set_order_type(OrderType::Limit)
+            .set_session(Session::Normal)
+            .set_duration(Duration::Day)
+            .set_price(150.0)
+            .add_order_leg(Instruction::Buy, AssetType::Equity, "AAPL", 1.0)
+    }
+
+    fn violations(order_builder: OrderBuilder) -> Vec<String> {
+        match order_builder.build() {
+            Err(TDAClientError::InvalidOrder(violations)) => violations,
+            other => panic!("Expected InvalidOrder, Got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn valid_order_builds() {
+        assert!(limit_order().build().is_ok());
+    }
+
+    #[test]
+    fn reports_every_violation() {
+        let order_builder = OrderBuilder::new()
+            .set_order_strategy_type(OrderStrategyType::Single)
+            .set_order_type(OrderType::StopLimit)
+            .set_quantity(0.0);
+
+        assert_eq!(
+            violations(order_builder),
+            vec![
+                "Order: Missing Order Legs",
+                "Order: Missing Session",
+                "Order: Missing Duration",
+                "Order: STOP_LIMIT Order Is Missing Price",
+                "Order: STOP_LIMIT Order Is Missing Stop Price",
+                "Order: Quantity Must Be Positive, Found 0",
+            ]
+        );
+    }
+
+    #[test]
+    fn trailing_stop_needs_offset_and_links() {
+        let order_builder = limit_order()
+            .set_order_type(OrderType::TrailingStop)
+            .set_stop_price_offset(1.0);
+
+        assert_eq!(
+            violations(order_builder),
+            vec![
+                "Order: TRAILING_STOP Order Is Missing Stop Price Link Basis",
+                "Order: TRAILING_STOP Order Is Missing Stop Price Link Type",
+            ]
+        );
+    }
+
+    #[test]
+    fn validates_child_order_strategies() {
+        let oco = OrderBuilder::new()
+            .set_order_strategy_type(OrderStrategyType::Oco)
+            .add_child_order_strategy(limit_order())
+            .add_child_order_strategy(limit_order().clear_price());
+
+        assert_eq!(
+            violations(oco),
+            vec!["Order > Child Order Strategy 1: LIMIT Order Is Missing Price"]
+        );
+
+        let oco = OrderBuilder::new()
+            .set_order_strategy_type(OrderStrategyType::Oco)
+            .add_child_order_strategy(limit_order())
+            .add_order_leg(Instruction::Buy, AssetType::Equity, "AAPL", 1.0);
+
+        assert_eq!(
+            violations(oco),
+            vec![
+                "Order: OCO Order Needs Exactly 2 Child Order Strategies, Found 1",
+                "Order: OCO Order Can't Have Order Legs",
+            ]
+        );
+
+        let trigger = limit_order().set_order_strategy_type(OrderStrategyType::Trigger);
+
+        assert_eq!(
+            violations(trigger),
+            vec!["Order: TRIGGER Order Needs A Child Order Strategy"]
+        );
+    }
+
+    #[test]
+    fn order_legs_need_positive_quantity() {
+        let order_builder =
+            limit_order().add_order_leg(Instruction::Buy, AssetType::Equity, "AMZN", -1.0);
+
+        assert_eq!(
+            violations(order_builder),
+            vec!["Order: Order Leg 1 (AMZN) Quantity Must Be Positive, Found -1"]
+        );
+    }
+
+    #[test]
+    fn rejects_non_finite_numbers() {
+        let order_builder = limit_order()
+            .set_quantity(f64::NAN)
+            .set_price(f64::INFINITY)
+            .set_stop_price(-1.0)
+            .add_order_leg(Instruction::Buy, AssetType::Equity, "AMZN", f64::NAN);
+
+        assert_eq!(
+            violations(order_builder),
+            vec![
+                "Order: Quantity Must Be A Finite Number, Found NaN",
+                "Order: Order Leg 1 (AMZN) Quantity Must Be A Finite Number, Found NaN",
+                "Order: Price Must Be A Finite Number, Found inf",
+                "Order: Stop Price Must Be Positive, Found -1",
+            ]
+        );
+    }
+
+    #[test]
+    fn linked_price_can_be_any_offset() {
+        let order_builder = limit_order()
+            .set_price_link_basis(PriceLinkBasis::Last)
+            .set_price(-0.5)
+            .set_stop_price_offset(-1.0);
+
+        assert!(order_builder.build().is_ok());
+        assert_eq!(
+            violations(
+                limit_order()
+                    .set_price_link_basis(PriceLinkBasis::Last)
+                    .set_price(f64::NAN)
+            ),
+            vec!["Order: Price Must Be A Finite Number, Found NaN"]
+        );
+    }
+
+    #[test]
+    fn missing_strategy_type_is_reported() {
+        assert_eq!(
+            violations(limit_order().clear_order_strategy_type()),
+            vec!["Order: Missing Order Strategy Type"]
+        );
     }
 }
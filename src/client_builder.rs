@@ -0,0 +1,371 @@
+use crate::asynchronous::auth_async::AsyncAuth;
+use crate::clock::{Clock, SystemClock};
+use crate::config::{
+    AuthConfig, ClientConfig, ReauthCallback, DEFAULT_ACCESS_TOKEN_MARGIN, DEFAULT_API_BASE_URL,
+    DEFAULT_AUTH_URL, DEFAULT_REFRESH_TOKEN_MARGIN,
+};
+use crate::rate_limiter::RateLimiter;
+use crate::retry::RetryPolicy;
+use crate::synchronous::auth_sync::SyncAuth;
+use crate::token::Token;
+use crate::token_store::{FileTokenStore, TokenStore};
+use crate::{
+    AsyncMarketDataClient, AsyncTDAClient, SyncMarketDataClient, SyncTDAClient, TDAClientError,
+};
+use reqwest::Proxy;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Builder to configure and create a `SyncTDAClient` or `AsyncTDAClient`, or a market data client with `TDAClientBuilder::market_data`
+///
+/// Parameters
+/// - client_id: Client id (consumer key)
+/// - redirect_uri: Redirect URI (callback URL)
+/// - token_path: Path to token file created by `init::create_token_file`. Use `with_token_store` or `set_token_store` to keep the token somewhere else
+/// - api_base_url: Base URL for API requests. Default is `https://api.tdameritrade.com/v1/`
+/// - auth_url: URL for OAuth token requests. Default is `https://api.tdameritrade.com/v1/oauth2/token`
+/// - reauth_callback: Called with the error when the refresh token is expired or rejected and a new token must be created with `init`. Runs while the client's auth lock is held, so it must not call the client (Optional)
+/// - access_token_margin: How long before the access token expires to refresh it. Default is 5 minutes
+/// - refresh_token_margin: How long before the refresh token expires to replace it. Default is 15 days
+/// - clock: Source of the current time for token expiry. Default is `SystemClock`
+/// - timeout: Total timeout for each request (Optional)
+/// - connect_timeout: Timeout for connecting to the server (Optional)
+/// - user_agent: User agent sent with each request (Optional)
+/// - proxy: Proxy for all requests (Optional)
+/// - rate_limiter: Delays (async) or blocks (sync) requests to stay under TD Ameritrade's limits. Shared by all clones of the client. Default is no rate limiting (Optional)
+/// - retry_policy: When to retry transient failures. Default is `RetryPolicy::default()`, which retries GET requests up to 3 times
+/// - reqwest_client: Caller-supplied `reqwest::Client` used by `build_async`. Ignores timeout, connect_timeout, user_agent, and proxy (Optional)
+/// - blocking_reqwest_client: Caller-supplied `reqwest::blocking::Client` used by `build_sync`. Ignores timeout, connect_timeout, user_agent, and proxy (Optional)
+#[derive(Debug)]
+pub struct TDAClientBuilder {
+    client_id: String,
+    redirect_uri: String,
+    token_store: Arc<dyn TokenStore>,
+    api_base_url: String,
+    auth_url: String,
+    reauth_callback: Option<ReauthCallback>,
+    access_token_margin: Duration,
+    refresh_token_margin: Duration,
+    clock: Arc<dyn Clock>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    proxy: Option<Proxy>,
+    rate_limiter: Option<RateLimiter>,
+    retry_policy: RetryPolicy,
+    reqwest_client: Option<reqwest::Client>,
+    blocking_reqwest_client: Option<reqwest::blocking::Client>,
+}
+
+impl TDAClientBuilder {
+    pub fn new(client_id: String, redirect_uri: String, token_path: String) -> TDAClientBuilder {
+        TDAClientBuilder::with_token_store(client_id, redirect_uri, FileTokenStore::new(token_path))
+    }
+
+    /// Builder for a market data client, which only needs the client id. Build it with `build_market_data_sync` or `build_market_data_async`
+    pub fn market_data(client_id: String) -> TDAClientBuilder {
+        TDAClientBuilder::new(client_id, String::new(), String::new())
+    }
+
+    pub fn with_token_store(
+        client_id: String,
+        redirect_uri: String,
+        token_store: impl TokenStore + 'static,
+    ) -> TDAClientBuilder {
+        TDAClientBuilder {
+            client_id,
+            redirect_uri,
+            token_store: Arc::new(token_store),
+            api_base_url: DEFAULT_API_BASE_URL.into(),
+            auth_url: DEFAULT_AUTH_URL.into(),
+            reauth_callback: None,
+            access_token_margin: DEFAULT_ACCESS_TOKEN_MARGIN,
+            refresh_token_margin: DEFAULT_REFRESH_TOKEN_MARGIN,
+            clock: Arc::new(SystemClock),
+            timeout: None,
+            connect_timeout: None,
+            user_agent: None,
+            proxy: None,
+            rate_limiter: None,
+            retry_policy: RetryPolicy::default(),
+            reqwest_client: None,
+            blocking_reqwest_client: None,
+        }
+    }
+
+    // Token Store
+    pub fn set_token_store(mut self, token_store: impl TokenStore + 'static) -> TDAClientBuilder {
+        self.token_store = Arc::new(token_store);
+        self
+    }
+
+    // API Base URL
+    pub fn set_api_base_url(mut self, api_base_url: &str) -> TDAClientBuilder {
+        self.api_base_url = api_base_url.to_string();
+        self
+    }
+
+    // Auth URL
+    pub fn set_auth_url(mut self, auth_url: &str) -> TDAClientBuilder {
+        self.auth_url = auth_url.to_string();
+        self
+    }
+
+    // Reauth Callback
+    pub fn set_reauth_callback(
+        mut self,
+        reauth_callback: impl Fn(&TDAClientError) + Send + Sync + 'static,
+    ) -> TDAClientBuilder {
+        self.reauth_callback = Some(ReauthCallback::new(reauth_callback));
+        self
+    }
+
+    // Access Token Margin
+    pub fn set_access_token_margin(mut self, access_token_margin: Duration) -> TDAClientBuilder {
+        self.access_token_margin = access_token_margin;
+        self
+    }
+
+    // Refresh Token Margin
+    pub fn set_refresh_token_margin(mut self, refresh_token_margin: Duration) -> TDAClientBuilder {
+        self.refresh_token_margin = refresh_token_margin;
+        self
+    }
+
+    // Clock
+    pub fn set_clock(mut self, clock: impl Clock + 'static) -> TDAClientBuilder {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    // Timeout
+    pub fn set_timeout(mut self, timeout: Duration) -> TDAClientBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    // Connect Timeout
+    pub fn set_connect_timeout(mut self, connect_timeout: Duration) -> TDAClientBuilder {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    // User Agent
+    pub fn set_user_agent(mut self, user_agent: &str) -> TDAClientBuilder {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    // Proxy
+    pub fn set_proxy(mut self, proxy: Proxy) -> TDAClientBuilder {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    // Rate Limiter
+    pub fn set_rate_limiter(mut self, rate_limiter: RateLimiter) -> TDAClientBuilder {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    // Retry Policy
+    pub fn set_retry_policy(mut self, retry_policy: RetryPolicy) -> TDAClientBuilder {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    // Reqwest Client
+    pub fn set_reqwest_client(mut self, reqwest_client: reqwest::Client) -> TDAClientBuilder {
+        self.reqwest_client = Some(reqwest_client);
+        self
+    }
+
+    // Blocking Reqwest Client
+    pub fn set_blocking_reqwest_client(
+        mut self,
+        blocking_reqwest_client: reqwest::blocking::Client,
+    ) -> TDAClientBuilder {
+        self.blocking_reqwest_client = Some(blocking_reqwest_client);
+        self
+    }
+
+    /// Build Synchronous TDAClient
+    pub fn build_sync(mut self) -> Result<SyncTDAClient, TDAClientError> {
+        // Use Caller-Supplied Reqwest Client Or Create One
+        let reqwest_client: reqwest::blocking::Client = self.blocking_reqwest_client()?;
+
+        // Create Auth Config
+        let auth_config: AuthConfig = AuthConfig {
+            auth_url: self.auth_url,
+            reauth_callback: self.reauth_callback,
+            access_token_margin: self.access_token_margin,
+            refresh_token_margin: self.refresh_token_margin,
+            clock: self.clock,
+        };
+
+        // Create Auth Client
+        let auth: SyncAuth = SyncAuth::new(
+            reqwest_client.clone(),
+            self.client_id,
+            self.redirect_uri,
+            self.token_store,
+            auth_config,
+        )?;
+
+        // Create Request Config
+        let config: ClientConfig = ClientConfig {
+            api_base_url: self.api_base_url,
+            rate_limiter: self.rate_limiter,
+            retry_policy: self.retry_policy,
+        };
+
+        Ok(SyncTDAClient::from_parts(reqwest_client, auth, config))
+    }
+
+    /// Build Asynchronous TDAClient
+    ///
+    /// Loads the token from the token store on the calling thread, which blocks on file I/O (and key derivation for `EncryptedFileTokenStore`). Use `build_async_nonblocking` inside a tokio runtime
+    pub fn build_async(self) -> Result<AsyncTDAClient, TDAClientError> {
+        // Load Token
+        let token: Token = self.token_store.load()?;
+
+        self.build_async_from_token(token)
+    }
+
+    /// Build Asynchronous TDAClient, loading the token on tokio's blocking thread pool so the runtime isn't blocked
+    pub async fn build_async_nonblocking(self) -> Result<AsyncTDAClient, TDAClientError> {
+        // Load Token Without Blocking The Runtime
+        let token_store: Arc<dyn TokenStore> = self.token_store.clone();
+        let token: Token = tokio::task::spawn_blocking(move || token_store.load())
+            .await
+            .map_err(|err| TDAClientError::TokenStoreError(err.to_string()))??;
+
+        self.build_async_from_token(token)
+    }
+
+    // Build Asynchronous TDAClient With An Already Loaded Token
+    fn build_async_from_token(mut self, token: Token) -> Result<AsyncTDAClient, TDAClientError> {
+        // Use Caller-Supplied Reqwest Client Or Create One
+        let reqwest_client: reqwest::Client = self.reqwest_client()?;
+
+        // Create Auth Config
+        let auth_config: AuthConfig = AuthConfig {
+            auth_url: self.auth_url,
+            reauth_callback: self.reauth_callback,
+            access_token_margin: self.access_token_margin,
+            refresh_token_margin: self.refresh_token_margin,
+            clock: self.clock,
+        };
+
+        // Create Auth Client
+        let auth: AsyncAuth = AsyncAuth::from_token(
+            reqwest_client.clone(),
+            self.client_id,
+            self.redirect_uri,
+            self.token_store,
+            auth_config,
+            token,
+        );
+
+        // Create Request Config
+        let config: ClientConfig = ClientConfig {
+            api_base_url: self.api_base_url,
+            rate_limiter: self.rate_limiter,
+            retry_policy: self.retry_policy,
+        };
+
+        Ok(AsyncTDAClient::from_parts(reqwest_client, auth, config))
+    }
+
+    /// Build Synchronous Market Data Client. Only the client id and request settings are used
+    pub fn build_market_data_sync(mut self) -> Result<SyncMarketDataClient, TDAClientError> {
+        // Use Caller-Supplied Reqwest Client Or Create One
+        let reqwest_client: reqwest::blocking::Client = self.blocking_reqwest_client()?;
+
+        // Create Request Config
+        let config: ClientConfig = ClientConfig {
+            api_base_url: self.api_base_url,
+            rate_limiter: self.rate_limiter,
+            retry_policy: self.retry_policy,
+        };
+
+        Ok(SyncMarketDataClient::from_parts(
+            reqwest_client,
+            self.client_id,
+            config,
+        ))
+    }
+
+    /// Build Asynchronous Market Data Client. Only the client id and request settings are used
+    pub fn build_market_data_async(mut self) -> Result<AsyncMarketDataClient, TDAClientError> {
+        // Use Caller-Supplied Reqwest Client Or Create One
+        let reqwest_client: reqwest::Client = self.reqwest_client()?;
+
+        // Create Request Config
+        let config: ClientConfig = ClientConfig {
+            api_base_url: self.api_base_url,
+            rate_limiter: self.rate_limiter,
+            retry_policy: self.retry_policy,
+        };
+
+        Ok(AsyncMarketDataClient::from_parts(
+            reqwest_client,
+            self.client_id,
+            config,
+        ))
+    }
+
+    // Use Caller-Supplied Blocking Reqwest Client Or Create One
+    fn blocking_reqwest_client(&mut self) -> Result<reqwest::blocking::Client, TDAClientError> {
+        if let Some(client) = self.blocking_reqwest_client.take() {
+            return Ok(client);
+        }
+
+        let mut client_builder = reqwest::blocking::Client::builder();
+
+        if let Some(timeout) = self.timeout {
+            client_builder = client_builder.timeout(timeout);
+        }
+
+        if let Some(connect_timeout) = self.connect_timeout {
+            client_builder = client_builder.connect_timeout(connect_timeout);
+        }
+
+        if let Some(user_agent) = &self.user_agent {
+            client_builder = client_builder.user_agent(user_agent.as_str());
+        }
+
+        if let Some(proxy) = self.proxy.take() {
+            client_builder = client_builder.proxy(proxy);
+        }
+
+        Ok(client_builder.build()?)
+    }
+
+    // Use Caller-Supplied Reqwest Client Or Create One
+    fn reqwest_client(&mut self) -> Result<reqwest::Client, TDAClientError> {
+        if let Some(client) = self.reqwest_client.take() {
+            return Ok(client);
+        }
+
+        let mut client_builder = reqwest::Client::builder();
+
+        if let Some(timeout) = self.timeout {
+            client_builder = client_builder.timeout(timeout);
+        }
+
+        if let Some(connect_timeout) = self.connect_timeout {
+            client_builder = client_builder.connect_timeout(connect_timeout);
+        }
+
+        if let Some(user_agent) = &self.user_agent {
+            client_builder = client_builder.user_agent(user_agent.as_str());
+        }
+
+        if let Some(proxy) = self.proxy.take() {
+            client_builder = client_builder.proxy(proxy);
+        }
+
+        Ok(client_builder.build()?)
+    }
+}
@@ -0,0 +1,80 @@
+use std::fmt::{self, Debug, Formatter};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Source of the current time for token expiry
+///
+/// `SystemClock` is used by default. Use `ManualClock` to simulate token expiry in tests
+pub trait Clock: Send + Sync {
+    /// Current epoch time in seconds
+    fn now(&self) -> u64;
+}
+
+impl Debug for dyn Clock {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Clock")
+    }
+}
+
+/// Clock that reads the system time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time somehow went backwards")
+            .as_secs()
+    }
+}
+
+/// Clock that only moves when advanced. Clones share the same time
+#[derive(Debug, Clone, Default)]
+pub struct ManualClock {
+    epoch_time: Arc<AtomicU64>,
+}
+
+impl ManualClock {
+    /// Create clock starting at an epoch time in seconds
+    pub fn new(epoch_time: u64) -> ManualClock {
+        ManualClock {
+            epoch_time: Arc::new(AtomicU64::new(epoch_time)),
+        }
+    }
+
+    /// Move the clock forward
+    pub fn advance(&self, duration: Duration) {
+        self.epoch_time
+            .fetch_add(duration.as_secs(), Ordering::SeqCst);
+    }
+
+    /// Set the clock to an epoch time in seconds
+    pub fn set(&self, epoch_time: u64) {
+        self.epoch_time.store(epoch_time, Ordering::SeqCst);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> u64 {
+        self.epoch_time.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manual_clock_only_moves_when_told() {
+        let clock = ManualClock::new(1000);
+        let clone = clock.clone();
+
+        clock.advance(Duration::from_secs(30));
+        assert_eq!(clone.now(), 1030);
+
+        clone.set(5);
+        assert_eq!(clock.now(), 5);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::clock::Clock;
+use crate::error::TDAClientError;
+use crate::rate_limiter::{RateLimitBucket, RateLimiter};
+use crate::retry::RetryPolicy;
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+
+// Default TD Ameritrade API URL
+pub(crate) static DEFAULT_API_BASE_URL: &str = "https://api.tdameritrade.com/v1/";
+
+// Default TD Ameritrade OAuth Token URL
+pub(crate) static DEFAULT_AUTH_URL: &str = "https://api.tdameritrade.com/v1/oauth2/token";
+
+// Default Time Before Expiry To Refresh The Access Token
+pub(crate) const DEFAULT_ACCESS_TOKEN_MARGIN: Duration = Duration::from_secs(300);
+
+// Default Time Before Expiry To Replace The Refresh Token
+pub(crate) const DEFAULT_REFRESH_TOKEN_MARGIN: Duration = Duration::from_secs(15 * 24 * 60 * 60);
+
+// Settings Shared By The Auth Layer Of Both Clients
+#[derive(Debug, Clone)]
+pub(crate) struct AuthConfig {
+    pub auth_url: String,
+    pub reauth_callback: Option<ReauthCallback>,
+    pub access_token_margin: Duration,
+    pub refresh_token_margin: Duration,
+    pub clock: Arc<dyn Clock>,
+}
+
+// Settings Shared By The Request Layer Of Both Clients
+#[derive(Debug, Clone)]
+pub(crate) struct ClientConfig {
+    pub api_base_url: String,
+    pub rate_limiter: Option<RateLimiter>,
+    pub retry_policy: RetryPolicy,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        ClientConfig {
+            api_base_url: DEFAULT_API_BASE_URL.into(),
+            rate_limiter: None,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+}
+
+impl ClientConfig {
+    // Format Full Url From Endpoint Path
+    pub fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.api_base_url.trim_end_matches('/'), path)
+    }
+
+    // Time To Wait Before Sending Request
+    pub fn rate_limit_delay(&self, is_get: bool, path: &str) -> Duration {
+        match &self.rate_limiter {
+            Some(rate_limiter) => rate_limiter.reserve(RateLimitBucket::for_request(is_get, path)),
+            None => Duration::ZERO,
+        }
+    }
+
+    // Time To Wait Before Retrying, Or None If The Request Shouldn't Be Retried
+    pub fn retry_delay(
+        &self,
+        is_get: bool,
+        attempt: u32,
+        err: &TDAClientError,
+    ) -> Option<Duration> {
+        self.retry_policy.delay(is_get, attempt, err)
+    }
+}
+
+// Called When The Token Can't Be Refreshed And A New One Must Be Created
+#[derive(Clone)]
+pub(crate) struct ReauthCallback(Arc<dyn Fn(&TDAClientError) + Send + Sync>);
+
+impl ReauthCallback {
+    pub fn new(callback: impl Fn(&TDAClientError) + Send + Sync + 'static) -> ReauthCallback {
+        ReauthCallback(Arc::new(callback))
+    }
+
+    pub fn call(&self, err: &TDAClientError) {
+        (self.0)(err)
+    }
+}
+
+impl Debug for ReauthCallback {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "ReauthCallback")
+    }
+}
@@ -1,3 +1,6 @@
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
+use serde::Deserialize;
 use std::fmt::{Display, Formatter, Result};
 
 #[derive(Debug)]
@@ -5,13 +8,74 @@ pub enum TDAClientError {
     /// Unable To Connect To TD Ameritrade API
     ClientConnectionError(reqwest::Error),
     /// Unable To Deserialize Output Into Struct
-    ClientParseError(serde_json::Error),
+    ClientParseError(ParseError),
     /// Unable To Find or Parse Token File
     TokenFileError(std::io::Error),
+    /// Unable To Load Or Save Token In Token Store
+    TokenStoreError(String),
+    /// 400: The request was malformed or rejected, e.g. an invalid order
+    BadRequest(String),
+    /// 401: The access token is invalid or expired
+    Unauthorized(String),
+    /// 403: The caller doesn't have access to the account or resource
+    Forbidden(String),
+    /// 404: The account, order, or resource doesn't exist
+    NotFound(String),
+    /// 429: Too many requests. `retry_after` is the number of seconds to wait, if TD Ameritrade sent one
+    RateLimited {
+        message: String,
+        retry_after: Option<u64>,
+    },
+    /// 5xx: TD Ameritrade had an internal error
+    ServerError { status: u16, message: String },
+    /// Any other unsuccessful status code
+    UnexpectedStatus { status: u16, message: String },
+    /// Order failed validation in `OrderBuilder::build`. Lists every problem found
+    InvalidOrder(Vec<String>),
+    /// Symbol isn't a valid TDA or OCC option symbol
+    InvalidOptionSymbol(String),
+    /// Unable To Get Authorization Code During Token Creation
+    AuthorizationError(String),
+    /// The refresh token is expired or was revoked. Create a new token with `init`
+    RefreshTokenExpired,
+    /// TD Ameritrade rejected the token refresh. Contains the server's `error_description`
+    RefreshRejected(String),
+    /// 429 from the token endpoint. `retry_after` is the number of seconds to wait, if TD Ameritrade sent one
+    TokenThrottled {
+        message: String,
+        retry_after: Option<u64>,
+    },
 }
 
 impl std::error::Error for TDAClientError {}
 
+/// Details of a response that couldn't be deserialized
+#[derive(Debug)]
+pub struct ParseError {
+    /// Endpoint that returned the response, if known
+    pub endpoint: Option<String>,
+    /// Start of the response body, if known
+    pub body_snippet: Option<String>,
+    /// Underlying serde error
+    pub source: serde_json::Error,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if let Some(endpoint) = &self.endpoint {
+            write!(f, "{}: ", endpoint)?;
+        }
+
+        write!(f, "{}", self.source)?;
+
+        if let Some(body_snippet) = &self.body_snippet {
+            write!(f, " (Body: {})", body_snippet)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl Display for TDAClientError {
     fn fmt(&self, f: &mut Formatter) -> Result {
         match self {
@@ -22,6 +86,52 @@ impl Display for TDAClientError {
             TDAClientError::TokenFileError(err) => {
                 write!(f, "Unable To Find Or Parse Token File: {}", err)
             }
+            TDAClientError::TokenStoreError(message) => {
+                write!(f, "Unable To Load Or Save Token: {}", message)
+            }
+            TDAClientError::BadRequest(message) => write!(f, "Bad Request: {}", message),
+            TDAClientError::Unauthorized(message) => write!(f, "Unauthorized: {}", message),
+            TDAClientError::Forbidden(message) => write!(f, "Forbidden: {}", message),
+            TDAClientError::NotFound(message) => write!(f, "Not Found: {}", message),
+            TDAClientError::RateLimited {
+                message,
+                retry_after,
+            } => match retry_after {
+                Some(secs) => write!(f, "Rate Limited: {} (Retry After {}s)", message, secs),
+                None => write!(f, "Rate Limited: {}", message),
+            },
+            TDAClientError::ServerError { status, message } => {
+                write!(f, "Server Error ({}): {}", status, message)
+            }
+            TDAClientError::UnexpectedStatus { status, message } => {
+                write!(f, "Unexpected Status ({}): {}", status, message)
+            }
+            TDAClientError::InvalidOrder(violations) => {
+                write!(f, "Invalid Order: {}", violations.join("; "))
+            }
+            TDAClientError::InvalidOptionSymbol(symbol) => {
+                write!(f, "Invalid Option Symbol: {}", symbol)
+            }
+            TDAClientError::AuthorizationError(message) => {
+                write!(f, "Authorization Failed: {}", message)
+            }
+            TDAClientError::RefreshTokenExpired => {
+                write!(f, "Refresh Token Expired. Create A New Token")
+            }
+            TDAClientError::RefreshRejected(message) => {
+                write!(f, "Token Refresh Rejected: {}", message)
+            }
+            TDAClientError::TokenThrottled {
+                message,
+                retry_after,
+            } => match retry_after {
+                Some(secs) => write!(
+                    f,
+                    "Token Refresh Throttled: {} (Retry After {}s)",
+                    message, secs
+                ),
+                None => write!(f, "Token Refresh Throttled: {}", message),
+            },
         }
     }
 }
@@ -34,7 +144,11 @@ impl From<reqwest::Error> for TDAClientError {
 
 impl From<serde_json::Error> for TDAClientError {
     fn from(err: serde_json::Error) -> Self {
-        TDAClientError::ClientParseError(err)
+        TDAClientError::ClientParseError(ParseError {
+            endpoint: None,
+            body_snippet: None,
+            source: err,
+        })
     }
 }
 
@@ -43,3 +157,213 @@ impl From<std::io::Error> for TDAClientError {
         TDAClientError::TokenFileError(err)
     }
 }
+
+// Error Body Returned By TD Ameritrade
+#[derive(Deserialize)]
+struct ErrorBody {
+    error: String,
+}
+
+impl TDAClientError {
+    // Convert An Unsuccessful Response Into An Error
+    pub(crate) fn from_status(status: StatusCode, headers: &HeaderMap, body: &str) -> Self {
+        // Use TD Ameritrade's Error Message If There Is One
+        let message: String = match serde_json::from_str::<ErrorBody>(body) {
+            Ok(error_body) => error_body.error,
+            Err(_) if !body.trim().is_empty() => body.trim().to_string(),
+            Err(_) => status.canonical_reason().unwrap_or_default().to_string(),
+        };
+
+        match status {
+            StatusCode::BAD_REQUEST => TDAClientError::BadRequest(message),
+            StatusCode::UNAUTHORIZED => TDAClientError::Unauthorized(message),
+            StatusCode::FORBIDDEN => TDAClientError::Forbidden(message),
+            StatusCode::NOT_FOUND => TDAClientError::NotFound(message),
+            StatusCode::TOO_MANY_REQUESTS => TDAClientError::RateLimited {
+                message,
+                retry_after: retry_after(headers),
+            },
+            s if s.is_server_error() => TDAClientError::ServerError {
+                status: s.as_u16(),
+                message,
+            },
+            s => TDAClientError::UnexpectedStatus {
+                status: s.as_u16(),
+                message,
+            },
+        }
+    }
+}
+
+// Error Body Returned By TD Ameritrade's OAuth Endpoint
+#[derive(Deserialize)]
+struct OAuthErrorBody {
+    error: String,
+    error_description: Option<String>,
+}
+
+impl TDAClientError {
+    /// Whether the token can't be refreshed and a new one must be created with `init`
+    pub fn requires_reauth(&self) -> bool {
+        matches!(
+            self,
+            TDAClientError::RefreshTokenExpired | TDAClientError::RefreshRejected(_)
+        )
+    }
+
+    // Convert An Unsuccessful Token Refresh Response Into An Error
+    pub(crate) fn from_token_response(status: StatusCode, headers: &HeaderMap, body: &str) -> Self {
+        // Throttled And Server Errors Are Temporary
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return TDAClientError::TokenThrottled {
+                message: body.trim().to_string(),
+                retry_after: retry_after(headers),
+            };
+        }
+
+        if status.is_server_error() {
+            return TDAClientError::from_status(status, headers, body);
+        }
+
+        // invalid_grant Means The Refresh Token Is Expired Or Revoked
+        match serde_json::from_str::<OAuthErrorBody>(body) {
+            Ok(error_body) if error_body.error == "invalid_grant" => {
+                TDAClientError::RefreshTokenExpired
+            }
+            Ok(error_body) => TDAClientError::RefreshRejected(
+                error_body.error_description.unwrap_or(error_body.error),
+            ),
+            Err(_) if !body.trim().is_empty() => {
+                TDAClientError::RefreshRejected(body.trim().into())
+            }
+            Err(_) => TDAClientError::RefreshRejected(
+                status.canonical_reason().unwrap_or_default().to_string(),
+            ),
+        }
+    }
+}
+
+// Number Of Seconds From The Retry-After Header
+fn retry_after(headers: &HeaderMap) -> Option<u64> {
+    headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn from_status(status: u16, body: &str) -> TDAClientError {
+        TDAClientError::from_status(
+            StatusCode::from_u16(status).unwrap(),
+            &HeaderMap::new(),
+            body,
+        )
+    }
+
+    #[test]
+    fn maps_client_error_statuses() {
+        let body = r#"{"error":"Order Rejected"}"#;
+
+        assert!(
+            matches!(from_status(400, body), TDAClientError::BadRequest(m) if m == "Order Rejected")
+        );
+        assert!(
+            matches!(from_status(401, body), TDAClientError::Unauthorized(m) if m == "Order Rejected")
+        );
+        assert!(
+            matches!(from_status(403, body), TDAClientError::Forbidden(m) if m == "Order Rejected")
+        );
+        assert!(
+            matches!(from_status(404, body), TDAClientError::NotFound(m) if m == "Order Rejected")
+        );
+    }
+
+    #[test]
+    fn falls_back_to_body_then_reason() {
+        assert!(
+            matches!(from_status(404, " Not Here \n"), TDAClientError::NotFound(m) if m == "Not Here")
+        );
+        assert!(matches!(from_status(404, ""), TDAClientError::NotFound(m) if m == "Not Found"));
+    }
+
+    #[test]
+    fn maps_rate_limit_with_retry_after() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static(" 30 "));
+
+        assert!(matches!(
+            TDAClientError::from_status(StatusCode::TOO_MANY_REQUESTS, &headers, ""),
+            TDAClientError::RateLimited {
+                retry_after: Some(30),
+                ..
+            }
+        ));
+
+        // HTTP Dates Aren't Supported
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+
+        assert!(matches!(
+            TDAClientError::from_status(StatusCode::TOO_MANY_REQUESTS, &headers, ""),
+            TDAClientError::RateLimited {
+                retry_after: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn maps_server_and_unexpected_statuses() {
+        assert!(matches!(
+            from_status(503, ""),
+            TDAClientError::ServerError { status: 503, .. }
+        ));
+        assert!(matches!(
+            from_status(418, ""),
+            TDAClientError::UnexpectedStatus { status: 418, .. }
+        ));
+    }
+
+    #[test]
+    fn maps_token_responses() {
+        let from_token_response = |status: u16, body: &str| {
+            TDAClientError::from_token_response(
+                StatusCode::from_u16(status).unwrap(),
+                &HeaderMap::new(),
+                body,
+            )
+        };
+
+        assert!(matches!(
+            from_token_response(400, r#"{"error":"invalid_grant"}"#),
+            TDAClientError::RefreshTokenExpired
+        ));
+        assert!(matches!(
+            from_token_response(401, r#"{"error":"invalid_client","error_description":"Bad Client"}"#),
+            TDAClientError::RefreshRejected(m) if m == "Bad Client"
+        ));
+        assert!(matches!(
+            from_token_response(400, "Bad"),
+            TDAClientError::RefreshRejected(m) if m == "Bad"
+        ));
+        assert!(matches!(
+            from_token_response(429, ""),
+            TDAClientError::TokenThrottled { .. }
+        ));
+        assert!(matches!(
+            from_token_response(500, ""),
+            TDAClientError::ServerError { status: 500, .. }
+        ));
+        assert!(from_token_response(400, r#"{"error":"invalid_grant"}"#).requires_reauth());
+        assert!(!from_token_response(429, "").requires_reauth());
+    }
+}
@@ -1,14 +1,42 @@
+use crate::clock::{Clock, SystemClock};
+use crate::config::DEFAULT_AUTH_URL;
+use crate::response;
 use crate::token::Token;
+use crate::token_store::{FileTokenStore, TokenStore};
+use crate::TDAClientError;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::OsRng;
+use native_tls::{Identity, TlsAcceptor};
 use reqwest::blocking::Client;
+use reqwest::Url;
 use std::collections::HashMap;
 use std::fs;
-use std::process::Command;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::io::{self, Read, Write};
+use std::net::TcpListener;
+use std::process::{Child, Command};
+use std::thread;
+use std::time::{Duration, Instant};
+use thirtyfour_sync::error::WebDriverError;
 use thirtyfour_sync::prelude::*;
-use urlencoding::decode;
+use tokio::task;
+use urlencoding::encode;
+
+// TD Ameritrade Login Page
+static AUTHORIZATION_URL: &str = "https://auth.tdameritrade.com/auth";
+
+// How Long To Wait For The User To Log In With Chromedriver
+const LOGIN_TIMEOUT: Duration = Duration::from_secs(600);
+
+// How Often To Check Whether The Browser Was Redirected
+const REDIRECT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+// Largest Request Head The Redirect Listener Will Read
+const MAX_REQUEST_HEAD_LEN: usize = 16 * 1024;
 
 /// Creates token file for subsequent use
 ///
+/// Panics if the token can't be created. Use `try_create_token_file` to get an error instead
+///
 /// Official Documentation: https://developer.tdameritrade.com/authentication/apis/post/token-0
 pub fn create_token_file(
     chromedriver_path: String,
@@ -16,85 +44,667 @@ pub fn create_token_file(
     redirect_uri: String,
     token_path: String,
 ) {
-    // Start ChromeDriver
-    let mut chrome_driver = Command::new(chromedriver_path)
-        .spawn()
-        .expect("Failed To Start Chrome Driver");
+    try_create_token_file(chromedriver_path, client_id, redirect_uri, token_path)
+        .unwrap_or_else(|err| panic!("Unable To Create Token File: {}", err))
+}
+
+/// Creates token file for subsequent use, returning an error if logging in or saving the token fails
+///
+/// Opens TD Ameritrade's login page with chromedriver and waits up to 10 minutes for the redirect. Chromedriver is stopped whether or not it succeeds
+///
+/// Official Documentation: https://developer.tdameritrade.com/authentication/apis/post/token-0
+pub fn try_create_token_file(
+    chromedriver_path: String,
+    client_id: String,
+    redirect_uri: String,
+    token_path: String,
+) -> Result<(), TDAClientError> {
+    // Start ChromeDriver. Stopped When Dropped, After The WebDriver Session Below
+    let _chrome_driver: ChromeDriver = ChromeDriver::start(&chromedriver_path)?;
 
     // Start WebDriver
     let chrome = DesiredCapabilities::chrome();
-    let driver =
-        WebDriver::new("http://localhost:9515", chrome).expect("Unable To Start WebDriver");
-
-    // Format URL
-    let code_url: String = format!(
-        "https://auth.tdameritrade.com/auth?response_type=code&client_id={}&redirect_uri={}",
-        client_id, redirect_uri
-    );
+    let driver = WebDriver::new("http://localhost:9515", chrome).map_err(webdriver_error)?;
 
     // Get Authentification Code
-    driver.get(code_url).expect("Unable To Go To Url");
-
-    let code: String;
-    let code_url: String = format!("{}/?code=", redirect_uri);
-
-    // Loop Until Code Is In URL
-    loop {
-        let url: String = driver.current_url().expect("Unable To Get Current URL");
-        if url.contains(&code_url) {
-            // Get Code
-            code = url.split("=").last().unwrap().to_string();
-            break;
-        }
-    }
+    let state: String = new_state();
+    driver
+        .get(authorization_url(&client_id, &redirect_uri, &state))
+        .map_err(webdriver_error)?;
 
-    // Decode Code
-    let decoded_code: String = decode(code.as_str()).unwrap().to_string();
+    let code: String = wait_for_redirect(&driver, &redirect_uri, &state)?;
 
     // Kill WebDriver
-    driver.quit().expect("Unable To Kill WebDriver");
+    driver.quit().map_err(webdriver_error)?;
+
+    // Exchange Code For Token
+    let token: Token = exchange_code(client_id, redirect_uri, &code)?;
+
+    // Write Token File
+    FileTokenStore::new(token_path).save(&token)
+}
+
+/// Creates token file for subsequent use without blocking the async runtime
+///
+/// Runs `try_create_token_file` on tokio's blocking thread pool
+///
+/// Official Documentation: https://developer.tdameritrade.com/authentication/apis/post/token-0
+pub async fn create_token_file_async(
+    chromedriver_path: String,
+    client_id: String,
+    redirect_uri: String,
+    token_path: String,
+) -> Result<(), TDAClientError> {
+    task::spawn_blocking(move || {
+        try_create_token_file(chromedriver_path, client_id, redirect_uri, token_path)
+    })
+    .await
+    .map_err(|err| {
+        TDAClientError::AuthorizationError(format!("Unable To Create Token File: {}", err))
+    })?
+}
+
+/// Creates token without chromedriver by listening on the redirect URI for TD Ameritrade's redirect
+///
+/// Prints the authorization URL, waits for the browser to be redirected to `redirect_uri` after logging in, and exchanges the code for a token. The redirect URI must use `http` and point at this machine, e.g. `http://127.0.0.1:8080`. Use `create_token_with_tls_listener` for an `https` redirect URI
+///
+/// Save the returned token with a `TokenStore`, e.g. `FileTokenStore::new(token_path).save(&token)`
+///
+/// Parameters
+/// - client_id: Client id (consumer key)
+/// - redirect_uri: Redirect URI (callback URL)
+pub fn create_token_with_listener(
+    client_id: String,
+    redirect_uri: String,
+) -> Result<Token, TDAClientError> {
+    // Print Authorization URL
+    let state: String = new_state();
+    print_authorization_url(&client_id, &redirect_uri, &state);
+
+    // Wait For Code
+    let code: String = listen_for_code(&redirect_uri, &state, None)?;
+
+    exchange_code(client_id, redirect_uri, &code)
+}
+
+/// Creates token without chromedriver by listening on an `https` redirect URI for TD Ameritrade's redirect
+///
+/// Same as `create_token_with_listener`, but serves HTTPS using the given certificate. A self-signed certificate works, but the browser will show a warning before continuing
+///
+/// Parameters
+/// - client_id: Client id (consumer key)
+/// - redirect_uri: Redirect URI (callback URL), e.g. `https://127.0.0.1`
+/// - cert_path: Path to PEM certificate (chain)
+/// - key_path: Path to PEM PKCS #8 private key
+pub fn create_token_with_tls_listener(
+    client_id: String,
+    redirect_uri: String,
+    cert_path: &str,
+    key_path: &str,
+) -> Result<Token, TDAClientError> {
+    // Load Certificate
+    let cert: Vec<u8> = fs::read(cert_path)?;
+    let key: Vec<u8> = fs::read(key_path)?;
+
+    let acceptor: TlsAcceptor = Identity::from_pkcs8(&cert, &key)
+        .and_then(TlsAcceptor::new)
+        .map_err(|err| {
+            TDAClientError::AuthorizationError(format!("Invalid Certificate: {}", err))
+        })?;
 
-    // Kill ChromeDriver
-    chrome_driver.kill().expect("Unable To Kill ChromeDriver");
+    // Print Authorization URL
+    let state: String = new_state();
+    print_authorization_url(&client_id, &redirect_uri, &state);
 
-    // Get Token URL
-    let token_url: &str = "https://api.tdameritrade.com/v1/oauth2/token";
+    // Wait For Code
+    let code: String = listen_for_code(&redirect_uri, &state, Some(acceptor))?;
 
+    exchange_code(client_id, redirect_uri, &code)
+}
+
+/// Creates token on a machine without a browser
+///
+/// Prints the authorization URL to open on any machine, then reads the URL the browser was redirected to from stdin. The redirected page doesn't have to load
+///
+/// Parameters
+/// - client_id: Client id (consumer key)
+/// - redirect_uri: Redirect URI (callback URL)
+pub fn create_token_from_redirect_url(
+    client_id: String,
+    redirect_uri: String,
+) -> Result<Token, TDAClientError> {
+    // Print Authorization URL
+    let state: String = new_state();
+    print_authorization_url(&client_id, &redirect_uri, &state);
+    println!("Paste The URL You Were Redirected To:");
+
+    // Read Redirected URL
+    let mut redirect_url: String = String::new();
+    io::stdin().read_line(&mut redirect_url).map_err(|err| {
+        TDAClientError::AuthorizationError(format!("Unable To Read URL: {}", err))
+    })?;
+
+    let code: String = parse_redirect_url(&redirect_url, &state)?;
+
+    exchange_code(client_id, redirect_uri, &code)
+}
+
+/// URL of TD Ameritrade's login page, which redirects to `redirect_uri` with a `code` and the same `state` after logging in
+///
+/// Parameters
+/// - client_id: Client id (consumer key)
+/// - redirect_uri: Redirect URI (callback URL)
+/// - state: Value the redirect must carry back, from `new_state`
+pub fn authorization_url(client_id: &str, redirect_uri: &str, state: &str) -> String {
+    format!(
+        "{}?response_type=code&client_id={}&redirect_uri={}&state={}",
+        AUTHORIZATION_URL,
+        encode(client_id),
+        encode(redirect_uri),
+        encode(state)
+    )
+}
+
+/// Random `state` for `authorization_url`, so a redirect that didn't come from this login is rejected
+pub fn new_state() -> String {
+    let mut state: [u8; 16] = [0; 16];
+    OsRng.fill_bytes(&mut state);
+
+    state.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Get the decoded `code` from the URL TD Ameritrade redirected to
+///
+/// Returns `TDAClientError::AuthorizationError` if the URL's `state` isn't the one passed to `authorization_url`
+pub fn parse_redirect_url(redirect_url: &str, state: &str) -> Result<String, TDAClientError> {
+    let url: Url = Url::parse(redirect_url.trim()).map_err(|err| {
+        TDAClientError::AuthorizationError(format!("Invalid Redirect URL: {}", err))
+    })?;
+
+    code_from_url(&url, state)
+}
+
+/// Exchange an authorization code for a token
+///
+/// Official Documentation: https://developer.tdameritrade.com/authentication/apis/post/token-0
+///
+/// Parameters
+/// - client_id: Client id (consumer key)
+/// - redirect_uri: Redirect URI (callback URL)
+/// - code: Decoded authorization code
+pub fn exchange_code(
+    client_id: String,
+    redirect_uri: String,
+    code: &str,
+) -> Result<Token, TDAClientError> {
+    exchange_code_with(
+        client_id,
+        redirect_uri,
+        code,
+        DEFAULT_AUTH_URL,
+        &SystemClock,
+    )
+}
+
+/// Exchange an authorization code for a token using a different OAuth endpoint or clock, e.g. a mock server and `ManualClock` in tests
+///
+/// Parameters
+/// - client_id: Client id (consumer key)
+/// - redirect_uri: Redirect URI (callback URL)
+/// - code: Decoded authorization code
+/// - auth_url: URL for OAuth token requests
+/// - clock: Source of the current time for the token's expire times
+pub fn exchange_code_with(
+    client_id: String,
+    redirect_uri: String,
+    code: &str,
+    auth_url: &str,
+    clock: &dyn Clock,
+) -> Result<Token, TDAClientError> {
     // Create Hashmap To Store Params
     let mut params: HashMap<String, String> = HashMap::new();
 
     // Parameters
     params.insert("grant_type".into(), "authorization_code".into());
     params.insert("access_type".into(), "offline".into());
-    params.insert("code".into(), decoded_code);
+    params.insert("code".into(), code.to_string());
     params.insert("client_id".into(), client_id);
-    params.insert("redirect_uri".into(), redirect_uri.clone());
-
-    // Create Reqwest Client
-    let reqwest_client: Client = Client::new();
+    params.insert("redirect_uri".into(), redirect_uri);
 
     // Post Request
-    let res = reqwest_client
-        .post(token_url)
-        .form(&params)
-        .send()
-        .expect("Post Request Failed");
+    let res = Client::new().post(auth_url).form(&params).send()?;
+
+    let status = res.status();
+    let headers = res.headers().clone();
+    let body: String = res.text()?;
+
+    if !status.is_success() {
+        return Err(TDAClientError::from_status(status, &headers, &body));
+    }
 
     // Convert JSON To Token
-    let mut token: Token = res
-        .json::<Token>()
-        .expect("Unable To Convert JSON To Token");
+    let mut token: Token = response::parse_json("POST oauth2/token", &body)?;
 
     // Get Current Time
-    let now: SystemTime = SystemTime::now();
-    let epoch_time: u64 = now
-        .duration_since(UNIX_EPOCH)
-        .expect("Time somehow went backwards")
-        .as_secs();
+    let epoch_time: u64 = clock.now();
 
-    // Replace Refresh Token Expire Time
-    token.replace_refresh_token_expire_time(epoch_time + 6480000); // Refresh Token Expires After 75 Days
+    // Set Expire Times
+    token.set_expire_times(epoch_time);
 
-    // Write Token File
-    fs::write(token_path, token.to_string()).expect("Unable To Write Token To File");
+    Ok(token)
+}
+
+// ChromeDriver Process, Killed When Dropped So Every Error Path Stops It
+struct ChromeDriver(Child);
+
+impl ChromeDriver {
+    fn start(chromedriver_path: &str) -> Result<ChromeDriver, TDAClientError> {
+        Command::new(chromedriver_path)
+            .spawn()
+            .map(ChromeDriver)
+            .map_err(|err| {
+                TDAClientError::AuthorizationError(format!(
+                    "Failed To Start Chrome Driver: {}",
+                    err
+                ))
+            })
+    }
+}
+
+impl Drop for ChromeDriver {
+    fn drop(&mut self) {
+        self.0.kill().ok();
+        self.0.wait().ok();
+    }
+}
+
+fn webdriver_error(err: WebDriverError) -> TDAClientError {
+    TDAClientError::AuthorizationError(format!("WebDriver: {}", err))
+}
+
+// Poll The Browser Until It's Redirected To The Redirect URI, Then Get The Code
+fn wait_for_redirect(
+    driver: &WebDriver,
+    redirect_uri: &str,
+    state: &str,
+) -> Result<String, TDAClientError> {
+    let deadline: Instant = Instant::now() + LOGIN_TIMEOUT;
+
+    while Instant::now() < deadline {
+        let url: String = driver.current_url().map_err(webdriver_error)?;
+
+        if url.starts_with(redirect_uri) {
+            return parse_redirect_url(&url, state);
+        }
+
+        thread::sleep(REDIRECT_POLL_INTERVAL);
+    }
+
+    Err(TDAClientError::AuthorizationError(
+        "Timed Out Waiting For Login".into(),
+    ))
+}
+
+// Print Authorization URL For The User To Open
+fn print_authorization_url(client_id: &str, redirect_uri: &str, state: &str) {
+    println!("Open This URL And Log In:");
+    println!("{}", authorization_url(client_id, redirect_uri, state));
+}
+
+// Whether The Redirected URL Carries The State Sent In The Authorization URL
+fn has_state(url: &Url, state: &str) -> bool {
+    url.query_pairs()
+        .any(|(name, value)| name == "state" && value == state)
+}
+
+// Get Code Or Error From Redirected URL
+fn code_from_url(url: &Url, state: &str) -> Result<String, TDAClientError> {
+    if !has_state(url, state) {
+        return Err(TDAClientError::AuthorizationError(
+            "State In Redirect URL Doesn't Match. Start Logging In Again".into(),
+        ));
+    }
+
+    let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
+
+    if let Some(code) = params.get("code") {
+        return Ok(code.clone());
+    }
+
+    match params.get("error") {
+        Some(error) => Err(TDAClientError::AuthorizationError(format!(
+            "{} {}",
+            error,
+            params
+                .get("error_description")
+                .map(String::as_str)
+                .unwrap_or_default()
+        ))),
+        None => Err(TDAClientError::AuthorizationError(
+            "No Code In Redirect URL".into(),
+        )),
+    }
+}
+
+// Accept Connections On The Redirect URI Until One Carries The Code
+fn listen_for_code(
+    redirect_uri: &str,
+    state: &str,
+    tls_acceptor: Option<TlsAcceptor>,
+) -> Result<String, TDAClientError> {
+    let url: Url = Url::parse(redirect_uri).map_err(|err| {
+        TDAClientError::AuthorizationError(format!("Invalid Redirect URI: {}", err))
+    })?;
+
+    // Listener Must Match The Redirect URI Scheme
+    match (url.scheme(), &tls_acceptor) {
+        ("http", None) | ("https", Some(_)) => {}
+        ("https", None) => {
+            return Err(TDAClientError::AuthorizationError(
+                "Use create_token_with_tls_listener For An https Redirect URI".into(),
+            ))
+        }
+        (scheme, _) => {
+            return Err(TDAClientError::AuthorizationError(format!(
+                "Unsupported Redirect URI Scheme: {}",
+                scheme
+            )))
+        }
+    }
+
+    let host: &str = url.host_str().unwrap_or("127.0.0.1");
+    let port: u16 = url.port_or_known_default().unwrap_or(80);
+
+    // Start Listener
+    let listener: TcpListener = TcpListener::bind((host, port)).map_err(|err| {
+        TDAClientError::AuthorizationError(format!(
+            "Unable To Listen On {}:{}: {}",
+            host, port, err
+        ))
+    })?;
+
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(_) => continue,
+        };
+
+        // Don't Let A Stalled Connection Block The Listener
+        stream.set_read_timeout(Some(Duration::from_secs(10))).ok();
+
+        let result: Option<Result<String, TDAClientError>> = match &tls_acceptor {
+            // Browser May Drop The Handshake Before Accepting A Self-Signed Certificate
+            Some(acceptor) => match acceptor.accept(stream) {
+                Ok(mut tls_stream) => handle_redirect(&mut tls_stream, url.path(), state),
+                Err(_) => None,
+            },
+            None => {
+                let mut stream = stream;
+                handle_redirect(&mut stream, url.path(), state)
+            }
+        };
+
+        if let Some(result) = result {
+            return result;
+        }
+    }
+
+    Err(TDAClientError::AuthorizationError(
+        "Redirect Listener Stopped".into(),
+    ))
+}
+
+// Answer One Request. Returns None If It Wasn't The Redirect
+fn handle_redirect<S: Read + Write>(
+    stream: &mut S,
+    redirect_path: &str,
+    state: &str,
+) -> Option<Result<String, TDAClientError>> {
+    // Read Request Head
+    let mut request: Vec<u8> = Vec::new();
+    let mut buffer: [u8; 1024] = [0; 1024];
+
+    while !request.windows(4).any(|window| window == b"\r\n\r\n")
+        && request.len() < MAX_REQUEST_HEAD_LEN
+    {
+        match stream.read(&mut buffer) {
+            Ok(0) | Err(_) => break,
+            Ok(n) => request.extend_from_slice(&buffer[..n]),
+        }
+    }
+
+    // Request Line Looks Like "GET /path?query HTTP/1.1"
+    let request: String = String::from_utf8_lossy(&request).to_string();
+    let target: Option<Url> = request
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|target| Url::parse("http://localhost").ok()?.join(target).ok());
+
+    // Ignore Other Requests, e.g. favicon.ico
+    let url: Url = match target {
+        Some(url)
+            if url.path().trim_end_matches('/') == redirect_path.trim_end_matches('/')
+                && url.query().is_some() =>
+        {
+            url
+        }
+        _ => {
+            respond(stream, "404 Not Found", "Not Found");
+            return None;
+        }
+    };
+
+    // Ignore Redirects From Other Logins, Which Could Otherwise Plant Someone Else's Code
+    if !has_state(&url, state) {
+        respond(stream, "400 Bad Request", "State Doesn't Match This Login");
+        return None;
+    }
+
+    let result: Result<String, TDAClientError> = code_from_url(&url, state);
+
+    match &result {
+        Ok(_) => respond(
+            stream,
+            "200 OK",
+            "Authorization Complete. You Can Close This Window",
+        ),
+        Err(err) => respond(stream, "400 Bad Request", &err.to_string()),
+    }
+
+    Some(result)
+}
+
+// Write A Plain Text Response
+fn respond<S: Write>(stream: &mut S, status: &str, body: &str) {
+    let response: String = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+
+    stream.write_all(response.as_bytes()).ok();
+    stream.flush().ok();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use crate::test_util::oauth_server;
+    use std::net::TcpStream;
+
+    // Send A GET Request To The Redirect Listener And Return The Status Line
+    fn redirect_request(port: u16, target: &str) -> String {
+        // Listener Starts On Another Thread
+        let mut stream: TcpStream = loop {
+            match TcpStream::connect(("127.0.0.1", port)) {
+                Ok(stream) => break stream,
+                Err(_) => thread::sleep(Duration::from_millis(10)),
+            }
+        };
+
+        write!(stream, "GET {} HTTP/1.1\r\nHost: 127.0.0.1\r\n\r\n", target).unwrap();
+
+        let mut response: String = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response.lines().next().unwrap_or_default().to_string()
+    }
+
+    #[test]
+    fn authorization_url_carries_state() {
+        let state: String = new_state();
+
+        assert_eq!(state.len(), 32);
+        assert_ne!(state, new_state());
+        assert_eq!(
+            authorization_url("client_id@AMER.OAUTHAP", "https://127.0.0.1", &state),
+            format!(
+                "https://auth.tdameritrade.com/auth?response_type=code&client_id=client_id%40AMER.OAUTHAP&redirect_uri=https%3A%2F%2F127.0.0.1&state={}",
+                state
+            )
+        );
+    }
+
+    #[test]
+    fn parses_percent_encoded_code() {
+        assert_eq!(
+            parse_redirect_url(
+                " https://127.0.0.1/?code=abc%2Bdef%2F12%3D&state=state \n",
+                "state"
+            )
+            .unwrap(),
+            "abc+def/12="
+        );
+    }
+
+    #[test]
+    fn rejects_redirect_without_code() {
+        assert!(matches!(
+            parse_redirect_url("https://127.0.0.1/?state=state", "state"),
+            Err(TDAClientError::AuthorizationError(message)) if message == "No Code In Redirect URL"
+        ));
+        assert!(matches!(
+            parse_redirect_url("not a url", "state"),
+            Err(TDAClientError::AuthorizationError(message)) if message.starts_with("Invalid Redirect URL")
+        ));
+    }
+
+    #[test]
+    fn reports_error_param() {
+        assert!(matches!(
+            parse_redirect_url(
+                "https://127.0.0.1/?error=access_denied&error_description=User+Denied&state=state",
+                "state"
+            ),
+            Err(TDAClientError::AuthorizationError(message)) if message == "access_denied User Denied"
+        ));
+    }
+
+    #[test]
+    fn rejects_wrong_or_missing_state() {
+        for redirect_url in [
+            "https://127.0.0.1/?code=code&state=other",
+            "https://127.0.0.1/?code=code",
+        ] {
+            assert!(matches!(
+                parse_redirect_url(redirect_url, "state"),
+                Err(TDAClientError::AuthorizationError(message)) if message.starts_with("State")
+            ));
+        }
+    }
+
+    #[test]
+    fn listener_waits_for_redirect_with_state() {
+        let port: u16 = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        let listener = thread::spawn(move || {
+            listen_for_code(
+                &format!("http://127.0.0.1:{}/callback", port),
+                "state",
+                None,
+            )
+        });
+
+        // Other Requests And Redirects From Other Logins Are Ignored
+        assert_eq!(
+            redirect_request(port, "/favicon.ico"),
+            "HTTP/1.1 404 Not Found"
+        );
+        assert_eq!(
+            redirect_request(port, "/callback?code=planted&state=other"),
+            "HTTP/1.1 400 Bad Request"
+        );
+        assert_eq!(
+            redirect_request(port, "/callback/?code=abc%2Bdef&state=state"),
+            "HTTP/1.1 200 OK"
+        );
+
+        assert_eq!(listener.join().unwrap().unwrap(), "abc+def");
+    }
+
+    #[test]
+    fn https_redirect_needs_tls_listener() {
+        assert!(matches!(
+            listen_for_code("https://127.0.0.1:0", "state", None),
+            Err(TDAClientError::AuthorizationError(message)) if message.contains("create_token_with_tls_listener")
+        ));
+    }
+
+    #[test]
+    fn missing_chromedriver_is_an_error() {
+        let result = try_create_token_file(
+            "/nonexistent/chromedriver".into(),
+            "client_id@AMER.OAUTHAP".into(),
+            "https://localhost".into(),
+            "token.json".into(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(TDAClientError::AuthorizationError(message)) if message.starts_with("Failed To Start Chrome Driver")
+        ));
+    }
+
+    #[tokio::test]
+    async fn async_token_file_creation_returns_errors() {
+        let result = create_token_file_async(
+            "/nonexistent/chromedriver".into(),
+            "client_id@AMER.OAUTHAP".into(),
+            "https://localhost".into(),
+            "token.json".into(),
+        )
+        .await;
+
+        assert!(matches!(result, Err(TDAClientError::AuthorizationError(_))));
+    }
+
+    #[test]
+    fn exchanged_token_expires_from_clock() {
+        let (auth_url, requests) = oauth_server(
+            r#"{"access_token": "access", "refresh_token": "refresh", "scope": "", "expires_in": 1800, "refresh_token_expires_in": 7776000, "token_type": "Bearer"}"#,
+        );
+        let clock = ManualClock::new(1_700_000_000);
+
+        let token: Token = exchange_code_with(
+            "client_id@AMER.OAUTHAP".into(),
+            "https://localhost".into(),
+            "code",
+            &auth_url,
+            &clock,
+        )
+        .unwrap();
+
+        let request: String = requests.try_recv().unwrap();
+        assert!(request.contains("grant_type=authorization_code"));
+        assert!(request.contains("code=code"));
+
+        assert_eq!(token.access_token_expire_time(), 1_700_000_000 + 1800);
+        assert_eq!(token.refresh_token_expire_time(), 1_700_000_000 + 7776000);
+    }
 }
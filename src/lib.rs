@@ -1,25 +1,73 @@
 // Builders
 mod builders;
+pub mod order_enums;
 pub mod order_templates;
 pub use builders::{OptionChainParams, OrderBuilder, PriceHistoryParams};
+mod client_builder;
+pub use client_builder::TDAClientBuilder;
+mod watchlist_builder;
+pub use watchlist_builder::{WatchlistBuilder, WatchlistItem, WatchlistPatchBuilder};
+
+// Option Symbols
+mod option_symbol;
+pub use option_symbol::{ExpirationDate, OptionSymbol, PutCall};
 
 // Asynchronous TDAClient
 mod asynchronous;
 pub use asynchronous::client_async::AsyncTDAClient;
+pub use asynchronous::market_data_async::AsyncMarketDataClient;
+pub use asynchronous::refresher_async::AsyncTokenRefresher;
 
 // Synchronous TDAClient
 mod synchronous;
 pub use synchronous::client_sync::SyncTDAClient;
+pub use synchronous::market_data_sync::SyncMarketDataClient;
+pub use synchronous::refresher_sync::SyncTokenRefresher;
+
+// Secrets
+mod secret;
+pub use secret::Secret;
+
+// Clock
+mod clock;
+pub use clock::{Clock, ManualClock, SystemClock};
 
 // Token
 mod token;
+pub use token::Token;
+mod token_store;
+pub use token_store::{
+    EncryptedFileTokenStore, EnvTokenStore, FileTokenStore, MemoryTokenStore, TokenStore,
+    TokenStoreLock,
+};
+
+// Market Data Requests
+mod market_data;
+
+// Response Helpers
+mod response;
+
+// Client Config
+mod config;
+
+// Rate Limiting
+mod rate_limiter;
+pub use rate_limiter::RateLimiter;
+
+// Retrying
+mod retry;
+pub use retry::RetryPolicy;
 
 // Output Structs
 pub mod output;
 
 // Error Handling
 mod error;
-pub use error::TDAClientError;
+pub use error::{ParseError, TDAClientError};
 
 // Create Token File
 pub mod init;
+
+// Test Helpers
+#[cfg(test)]
+mod test_util;
@@ -0,0 +1,219 @@
+use crate::builders::{OptionChain, PriceHistory};
+use std::collections::HashMap;
+
+// URL And Query Parameters For A Market Data Endpoint. Shared By The Authenticated And Market Data Clients
+pub(crate) struct MarketDataRequest {
+    pub url: String,
+    pub params: HashMap<String, String>,
+}
+
+impl MarketDataRequest {
+    //// Instruments ////
+
+    pub fn search_instruments(symbol: &str, projection: &str) -> MarketDataRequest {
+        // Create HashMap To Store Parameters
+        let mut params: HashMap<String, String> = HashMap::new();
+
+        // Parameters
+        params.insert("symbol".into(), symbol.into());
+        params.insert("projection".into(), projection.into());
+
+        // Format URL
+        let url: String = "instruments".into();
+
+        MarketDataRequest { url, params }
+    }
+
+    pub fn get_instrument(cusip: &str) -> MarketDataRequest {
+        // Create HashMap To Store Parameters
+        let params: HashMap<String, String> = HashMap::new();
+
+        // Format URL
+        let url: String = format!("instruments/{}", cusip);
+
+        MarketDataRequest { url, params }
+    }
+
+    //// Market Hours ////
+
+    pub fn get_hours_for_multiple_markets(markets: &[&str], date: &str) -> MarketDataRequest {
+        // Create HashMap To Store Parameters
+        let mut params: HashMap<String, String> = HashMap::new();
+
+        // Convert Vector To String
+        let cleaned_markets: String = markets.join(",");
+
+        // Parameters
+        params.insert("markets".into(), cleaned_markets);
+        params.insert("date".into(), date.into());
+
+        // Format URL
+        let url: String = "marketdata/hours".into();
+
+        MarketDataRequest { url, params }
+    }
+
+    pub fn get_hours_for_single_market(market: &str, date: &str) -> MarketDataRequest {
+        // Create HashMap To Store Parameters
+        let mut params: HashMap<String, String> = HashMap::new();
+
+        // Parameter
+        params.insert("date".into(), date.into());
+
+        // Format URL
+        let url: String = format!("marketdata/{}/hours", market);
+
+        MarketDataRequest { url, params }
+    }
+
+    //// Movers ////
+
+    pub fn get_movers(index: &str, direction: &str, change: &str) -> MarketDataRequest {
+        // Create HashMap To Store Parameters
+        let mut params: HashMap<String, String> = HashMap::new();
+
+        // Parameters
+        params.insert("direction".into(), direction.into());
+        params.insert("change".into(), change.into());
+
+        // Format URL
+        let url: String = format!("marketdata/{}/movers", index);
+
+        MarketDataRequest { url, params }
+    }
+
+    //// Option Chains ////
+
+    pub fn get_option_chain(option_params: &OptionChain) -> MarketDataRequest {
+        // Create HashMap To Store Parameters
+        let mut params: HashMap<String, String> = HashMap::new();
+
+        // Optional Parameters
+        if option_params.strike_count != -1 {
+            params.insert("strikeCount".into(), option_params.strike_count.to_string());
+        }
+
+        if option_params.interval != -1.0 {
+            params.insert("interval".into(), option_params.interval.to_string());
+        }
+
+        if option_params.strike != -1.0 {
+            params.insert("strike".into(), option_params.strike.to_string());
+        }
+
+        if !option_params.from_date.is_empty() {
+            params.insert("fromDate".into(), option_params.from_date.clone());
+        }
+
+        if !option_params.to_date.is_empty() {
+            params.insert("toDate".into(), option_params.to_date.clone());
+        }
+
+        if option_params.volatility != -1.0 {
+            params.insert("volatility".into(), option_params.volatility.to_string());
+        }
+
+        if option_params.underlying_price != -1.0 {
+            params.insert(
+                "underlyingPrice".into(),
+                option_params.underlying_price.to_string(),
+            );
+        }
+
+        if option_params.interest_rate != -1.0 {
+            params.insert(
+                "interestRate".into(),
+                option_params.interest_rate.to_string(),
+            );
+        }
+
+        if option_params.days_to_expiration != -1 {
+            params.insert(
+                "daysToExpiration".into(),
+                option_params.days_to_expiration.to_string(),
+            );
+        }
+
+        // Required Parameters
+        params.insert("symbol".into(), option_params.symbol.clone());
+        params.insert("contractType".into(), option_params.contract_type.clone());
+        params.insert(
+            "includeQuotes".into(),
+            option_params.include_quotes.to_string(),
+        );
+        params.insert("strategy".into(), option_params.strategy.clone());
+        params.insert("range".into(), option_params.range.clone());
+        params.insert("expMonth".into(), option_params.expiration_month.clone());
+        params.insert("optionType".into(), option_params.option_type.clone());
+
+        // Format URL
+        let url: String = "marketdata/chains".into();
+
+        MarketDataRequest { url, params }
+    }
+
+    //// Price History ////
+
+    pub fn get_price_history(history_params: &PriceHistory) -> MarketDataRequest {
+        // Create HashMap To Store Parameters
+        let mut params: HashMap<String, String> = HashMap::new();
+
+        // Optional Parameters
+        if history_params.period != -1 {
+            params.insert("period".into(), history_params.period.to_string());
+        }
+
+        if history_params.start_date != -1 {
+            params.insert("startDate".into(), history_params.start_date.to_string());
+        }
+
+        if history_params.end_date != -1 {
+            params.insert("endDate".into(), history_params.end_date.to_string());
+        }
+
+        // Required Parameters
+        params.insert("periodType".into(), history_params.period_type.clone());
+        params.insert(
+            "frequencyType".into(),
+            history_params.frequency_type.clone(),
+        );
+        params.insert("frequency".into(), history_params.frequency.to_string());
+        params.insert(
+            "needExtendedHoursData".into(),
+            history_params.need_extended_hours_data.to_string(),
+        );
+
+        // Format URL
+        let url: String = format!("marketdata/{}/pricehistory", history_params.symbol);
+
+        MarketDataRequest { url, params }
+    }
+
+    //// Quotes ////
+
+    pub fn get_quote(symbol: &str) -> MarketDataRequest {
+        // Create HashMap To Store Parameters
+        let params: HashMap<String, String> = HashMap::new();
+
+        // Format URL
+        let url: String = format!("marketdata/{}/quotes", symbol);
+
+        MarketDataRequest { url, params }
+    }
+
+    pub fn get_quotes(symbols: &[&str]) -> MarketDataRequest {
+        // Create HashMap To Store Parameters
+        let mut params: HashMap<String, String> = HashMap::new();
+
+        // Convert Vector To String
+        let cleaned_symbols: String = symbols.join(",");
+
+        // Parameter
+        params.insert("symbol".into(), cleaned_symbols);
+
+        // Format URL
+        let url: String = "marketdata/quotes".into();
+
+        MarketDataRequest { url, params }
+    }
+}
@@ -0,0 +1,297 @@
+use crate::output::option_chains::OptionData;
+use crate::TDAClientError;
+use std::fmt::{Display, Formatter, Result};
+use std::str::FromStr;
+
+/// Option contract identified by underlying, expiration, put/call, and strike
+///
+/// Parses and formats TDA option symbols (e.g. `AAPL_012023C150`, as returned in `OptionData::symbol` and used in order legs) and converts to and from the 21 character OCC format (e.g. `AAPL  230120C00150000`)
+///
+/// Displays as the TDA option symbol, so it can be passed to `OrderBuilder::add_order_leg`
+///
+/// Parameters
+/// - underlying: Underlying symbol, e.g. `AAPL`
+/// - expiration: Expiration date
+/// - put_call: `Put` or `Call`
+/// - strike: Strike price
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptionSymbol {
+    pub underlying: String,
+    pub expiration: ExpirationDate,
+    pub put_call: PutCall,
+    pub strike: f64,
+}
+
+/// Option expiration date. Orders by date
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExpirationDate {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+/// Whether an option is a put or a call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PutCall {
+    Put,
+    Call,
+}
+
+impl OptionSymbol {
+    /// Create option symbol
+    ///
+    /// Returns `TDAClientError::InvalidOptionSymbol` if the expiration isn't a real date between 2000 and 2099 or the strike isn't positive
+    pub fn new(
+        underlying: &str,
+        expiration: ExpirationDate,
+        put_call: PutCall,
+        strike: f64,
+    ) -> std::result::Result<Self, TDAClientError> {
+        let option_symbol = OptionSymbol {
+            underlying: underlying.to_string(),
+            expiration,
+            put_call,
+            strike,
+        };
+
+        let valid_date = (2000..=2099).contains(&expiration.year)
+            && (1..=days_in_month(expiration.year, expiration.month)).contains(&expiration.day);
+
+        if underlying.is_empty() || !valid_date || !strike.is_finite() || strike <= 0.0 {
+            return Err(TDAClientError::InvalidOptionSymbol(
+                option_symbol.to_string(),
+            ));
+        }
+
+        Ok(option_symbol)
+    }
+
+    /// Parse OCC option symbol, e.g. `AAPL  230120C00150000`
+    ///
+    /// The underlying may be padded to 6 characters with spaces or left unpadded
+    pub fn from_occ(symbol: &str) -> std::result::Result<Self, TDAClientError> {
+        let invalid = || TDAClientError::InvalidOptionSymbol(symbol.to_string());
+
+        // Underlying, Then YYMMDD, Put/Call, And Strike x 1000 In 8 Digits
+        let split = symbol.len().checked_sub(15).ok_or_else(invalid)?;
+        let (underlying, contract) = (
+            symbol.get(..split).ok_or_else(invalid)?.trim_end(),
+            symbol.get(split..).ok_or_else(invalid)?,
+        );
+
+        if !contract.is_ascii()
+            || !contract[..6].bytes().all(|byte| byte.is_ascii_digit())
+            || !contract[7..].bytes().all(|byte| byte.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let expiration = ExpirationDate {
+            year: 2000 + contract[0..2].parse::<u16>().map_err(|_| invalid())?,
+            month: contract[2..4].parse().map_err(|_| invalid())?,
+            day: contract[4..6].parse().map_err(|_| invalid())?,
+        };
+        let put_call = parse_put_call(&contract[6..7]).ok_or_else(invalid)?;
+        let strike = contract[7..].parse::<u64>().map_err(|_| invalid())? as f64 / 1000.0;
+
+        OptionSymbol::new(underlying, expiration, put_call, strike).map_err(|_| invalid())
+    }
+
+    /// Format as 21 character OCC option symbol, e.g. `AAPL  230120C00150000`
+    ///
+    /// Returns `TDAClientError::InvalidOptionSymbol` if the underlying is longer than 6 characters or the strike doesn't fit in 8 digits
+    pub fn to_occ(&self) -> std::result::Result<String, TDAClientError> {
+        let strike = (self.strike * 1000.0).round() as u64;
+
+        if self.underlying.chars().count() > 6 || strike > 99_999_999 {
+            return Err(TDAClientError::InvalidOptionSymbol(self.to_string()));
+        }
+
+        Ok(format!(
+            "{:<6}{:02}{:02}{:02}{}{:08}",
+            self.underlying,
+            self.expiration.year % 100,
+            self.expiration.month,
+            self.expiration.day,
+            put_call_code(self.put_call),
+            strike
+        ))
+    }
+}
+
+// Parse TDA Option Symbol, e.g. AAPL_012023C150
+impl FromStr for OptionSymbol {
+    type Err = TDAClientError;
+
+    fn from_str(symbol: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = || TDAClientError::InvalidOptionSymbol(symbol.to_string());
+
+        // Underlying, Then MMDDYY, Put/Call, And Strike
+        let (underlying, contract) = symbol.split_once('_').ok_or_else(invalid)?;
+        let expiration = contract.get(..6).ok_or_else(invalid)?;
+
+        if !expiration.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(invalid());
+        }
+
+        let expiration = ExpirationDate {
+            year: 2000 + expiration[4..6].parse::<u16>().map_err(|_| invalid())?,
+            month: expiration[0..2].parse().map_err(|_| invalid())?,
+            day: expiration[2..4].parse().map_err(|_| invalid())?,
+        };
+        let put_call =
+            parse_put_call(contract.get(6..7).ok_or_else(invalid)?).ok_or_else(invalid)?;
+        let strike = contract.get(7..).ok_or_else(invalid)?;
+
+        // Only Digits With At Most One Decimal Point, So f64 Syntax Like 1e2 Or +150 Isn't Accepted
+        if !strike
+            .bytes()
+            .all(|byte| byte.is_ascii_digit() || byte == b'.')
+            || strike.matches('.').count() > 1
+        {
+            return Err(invalid());
+        }
+
+        let strike = strike.parse::<f64>().map_err(|_| invalid())?;
+
+        OptionSymbol::new(underlying, expiration, put_call, strike).map_err(|_| invalid())
+    }
+}
+
+// Format As TDA Option Symbol, e.g. AAPL_012023C150
+impl Display for OptionSymbol {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(
+            f,
+            "{}_{:02}{:02}{:02}{}{}",
+            self.underlying,
+            self.expiration.month,
+            self.expiration.day,
+            self.expiration.year % 100,
+            put_call_code(self.put_call),
+            self.strike
+        )
+    }
+}
+
+// Option Chain Contracts
+impl TryFrom<&OptionData> for OptionSymbol {
+    type Error = TDAClientError;
+
+    fn try_from(option_data: &OptionData) -> std::result::Result<Self, Self::Error> {
+        option_data.symbol.parse()
+    }
+}
+
+// Number Of Days In Month, Or 0 If The Month Doesn't Exist
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        _ => 0,
+    }
+}
+
+fn parse_put_call(code: &str) -> Option<PutCall> {
+    match code {
+        "C" => Some(PutCall::Call),
+        "P" => Some(PutCall::Put),
+        _ => None,
+    }
+}
+
+fn put_call_code(put_call: PutCall) -> char {
+    match put_call {
+        PutCall::Call => 'C',
+        PutCall::Put => 'P',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tda_symbol_round_trips() {
+        for symbol in ["AAPL_012023C150", "SPY_122024P472.5", "BRKB_061521C0.5"] {
+            let option_symbol: OptionSymbol = symbol.parse().unwrap();
+            assert_eq!(option_symbol.to_string(), symbol);
+        }
+    }
+
+    #[test]
+    fn tda_symbol_parses_fields() {
+        let option_symbol: OptionSymbol = "AAPL_012023C150".parse().unwrap();
+
+        assert_eq!(option_symbol.underlying, "AAPL");
+        assert_eq!(
+            option_symbol.expiration,
+            ExpirationDate {
+                year: 2023,
+                month: 1,
+                day: 20
+            }
+        );
+        assert_eq!(option_symbol.put_call, PutCall::Call);
+        assert_eq!(option_symbol.strike, 150.0);
+    }
+
+    #[test]
+    fn occ_symbol_round_trips() {
+        let option_symbol = OptionSymbol::from_occ("AAPL  230120C00150000").unwrap();
+
+        assert_eq!(option_symbol.to_string(), "AAPL_012023C150");
+        assert_eq!(option_symbol.to_occ().unwrap(), "AAPL  230120C00150000");
+        assert_eq!(
+            OptionSymbol::from_occ("SPY241220P00472500")
+                .unwrap()
+                .to_occ()
+                .unwrap(),
+            "SPY   241220P00472500"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_symbols() {
+        for symbol in [
+            "AAPL",
+            "AAPL_012023X150",
+            "AAPL_012023C",
+            "AAPL_012023C-1",
+            "_012023C150",
+            "AAPL_132023C150",
+            "X_023123C1",
+            "X_022923C1",
+            "AAPL_012023C1e2",
+            "AAPL_012023C+150",
+            "AAPL_012023C1.5.0",
+            "AAPL_012023Cinf",
+        ] {
+            assert!(symbol.parse::<OptionSymbol>().is_err(), "{}", symbol);
+        }
+
+        assert!(OptionSymbol::from_occ("X     230231C00001000").is_err());
+        assert!(OptionSymbol::from_occ("SPY2412é0P00472500").is_err());
+    }
+
+    #[test]
+    fn accepts_leap_day() {
+        assert!("SPY_022924C400".parse::<OptionSymbol>().is_ok());
+    }
+
+    #[test]
+    fn to_occ_rejects_what_occ_cant_fit() {
+        let long_root: OptionSymbol = "GOOGLE1_012023C150".parse().unwrap();
+        assert!(matches!(
+            long_root.to_occ(),
+            Err(TDAClientError::InvalidOptionSymbol(symbol)) if symbol == "GOOGLE1_012023C150"
+        ));
+
+        let huge_strike: OptionSymbol = "BRKA_012023C500000".parse().unwrap();
+        assert!(huge_strike.to_occ().is_err());
+    }
+}
@@ -0,0 +1,418 @@
+use serde::{Deserialize, Serialize};
+
+/// The market session when an order should be executed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Session {
+    Normal,
+    Am,
+    Pm,
+    Seamless,
+}
+
+/// Length of time an order will be active
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Duration {
+    Day,
+    GoodTillCancel,
+    FillOrKill,
+}
+
+/// Type of order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderType {
+    Market,
+    Limit,
+    Stop,
+    StopLimit,
+    TrailingStop,
+    MarketOnClose,
+    Exercise,
+    TrailingStopLimit,
+    NetDebit,
+    NetCredit,
+    NetZero,
+}
+
+/// Strategy type for complex orders
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ComplexOrderStrategyType {
+    None,
+    Covered,
+    Vertical,
+    BackRatio,
+    Calendar,
+    Diagonal,
+    Straddle,
+    Strangle,
+    CollarSynthetic,
+    Butterfly,
+    Condor,
+    IronCondor,
+    VerticalRoll,
+    CollarWithStock,
+    DoubleDiagonal,
+    UnbalancedButterfly,
+    UnbalancedCondor,
+    UnbalancedIronCondor,
+    UnbalancedVerticalRoll,
+    Custom,
+}
+
+/// Preferred order destination
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RequestedDestination {
+    Inet,
+    EcnArca,
+    Cboe,
+    Amex,
+    Phlx,
+    Ise,
+    Box,
+    Nyse,
+    Nasdaq,
+    Bats,
+    C2,
+    Auto,
+}
+
+/// Price the stop price is linked to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StopPriceLinkBasis {
+    Manual,
+    Base,
+    Trigger,
+    Last,
+    Bid,
+    Ask,
+    AskBid,
+    Mark,
+    Average,
+}
+
+/// How the stop price offset is measured
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StopPriceLinkType {
+    Value,
+    Percent,
+    Tick,
+}
+
+/// Price that triggers a stop order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StopType {
+    Standard,
+    Bid,
+    Ask,
+    Last,
+    Mark,
+}
+
+/// Price the order price is linked to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PriceLinkBasis {
+    Manual,
+    Base,
+    Trigger,
+    Last,
+    Bid,
+    Ask,
+    AskBid,
+    Mark,
+    Average,
+}
+
+/// How the order price offset is measured
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PriceLinkType {
+    Value,
+    Percent,
+    Tick,
+}
+
+/// Special instruction for an order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SpecialInstruction {
+    AllOrNone,
+    DoNotReduce,
+    AllOrNoneDoNotReduce,
+}
+
+/// Strategy type for composite orders
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderStrategyType {
+    Single,
+    Oco,
+    Trigger,
+}
+
+/// Type of trade for an order leg
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Instruction {
+    Buy,
+    Sell,
+    BuyToCover,
+    SellShort,
+    BuyToOpen,
+    BuyToClose,
+    SellToOpen,
+    SellToClose,
+    Exchange,
+}
+
+/// Type of asset traded in an order leg
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AssetType {
+    Equity,
+    Option,
+    Index,
+    MutualFund,
+    CashEquivalent,
+    FixedIncome,
+    Currency,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::de::DeserializeOwned;
+    use std::fmt::Debug;
+
+    // Each Variant Serializes To The API String And Deserializes Back
+    fn assert_api_strings<T>(cases: &[(T, &str)])
+    where
+        T: Serialize + DeserializeOwned + PartialEq + Debug,
+    {
+        for (variant, api_string) in cases {
+            let json: String = format!("\"{}\"", api_string);
+
+            assert_eq!(serde_json::to_string(variant).unwrap(), json);
+            assert_eq!(&serde_json::from_str::<T>(&json).unwrap(), variant);
+        }
+    }
+
+    #[test]
+    fn session() {
+        assert_api_strings(&[
+            (Session::Normal, "NORMAL"),
+            (Session::Am, "AM"),
+            (Session::Pm, "PM"),
+            (Session::Seamless, "SEAMLESS"),
+        ]);
+    }
+
+    #[test]
+    fn duration() {
+        assert_api_strings(&[
+            (Duration::Day, "DAY"),
+            (Duration::GoodTillCancel, "GOOD_TILL_CANCEL"),
+            (Duration::FillOrKill, "FILL_OR_KILL"),
+        ]);
+    }
+
+    #[test]
+    fn order_type() {
+        assert_api_strings(&[
+            (OrderType::Market, "MARKET"),
+            (OrderType::Limit, "LIMIT"),
+            (OrderType::Stop, "STOP"),
+            (OrderType::StopLimit, "STOP_LIMIT"),
+            (OrderType::TrailingStop, "TRAILING_STOP"),
+            (OrderType::MarketOnClose, "MARKET_ON_CLOSE"),
+            (OrderType::Exercise, "EXERCISE"),
+            (OrderType::TrailingStopLimit, "TRAILING_STOP_LIMIT"),
+            (OrderType::NetDebit, "NET_DEBIT"),
+            (OrderType::NetCredit, "NET_CREDIT"),
+            (OrderType::NetZero, "NET_ZERO"),
+        ]);
+    }
+
+    #[test]
+    fn complex_order_strategy_type() {
+        assert_api_strings(&[
+            (ComplexOrderStrategyType::None, "NONE"),
+            (ComplexOrderStrategyType::Covered, "COVERED"),
+            (ComplexOrderStrategyType::Vertical, "VERTICAL"),
+            (ComplexOrderStrategyType::BackRatio, "BACK_RATIO"),
+            (ComplexOrderStrategyType::Calendar, "CALENDAR"),
+            (ComplexOrderStrategyType::Diagonal, "DIAGONAL"),
+            (ComplexOrderStrategyType::Straddle, "STRADDLE"),
+            (ComplexOrderStrategyType::Strangle, "STRANGLE"),
+            (
+                ComplexOrderStrategyType::CollarSynthetic,
+                "COLLAR_SYNTHETIC",
+            ),
+            (ComplexOrderStrategyType::Butterfly, "BUTTERFLY"),
+            (ComplexOrderStrategyType::Condor, "CONDOR"),
+            (ComplexOrderStrategyType::IronCondor, "IRON_CONDOR"),
+            (ComplexOrderStrategyType::VerticalRoll, "VERTICAL_ROLL"),
+            (
+                ComplexOrderStrategyType::CollarWithStock,
+                "COLLAR_WITH_STOCK",
+            ),
+            (ComplexOrderStrategyType::DoubleDiagonal, "DOUBLE_DIAGONAL"),
+            (
+                ComplexOrderStrategyType::UnbalancedButterfly,
+                "UNBALANCED_BUTTERFLY",
+            ),
+            (
+                ComplexOrderStrategyType::UnbalancedCondor,
+                "UNBALANCED_CONDOR",
+            ),
+            (
+                ComplexOrderStrategyType::UnbalancedIronCondor,
+                "UNBALANCED_IRON_CONDOR",
+            ),
+            (
+                ComplexOrderStrategyType::UnbalancedVerticalRoll,
+                "UNBALANCED_VERTICAL_ROLL",
+            ),
+            (ComplexOrderStrategyType::Custom, "CUSTOM"),
+        ]);
+    }
+
+    #[test]
+    fn requested_destination() {
+        assert_api_strings(&[
+            (RequestedDestination::Inet, "INET"),
+            (RequestedDestination::EcnArca, "ECN_ARCA"),
+            (RequestedDestination::Cboe, "CBOE"),
+            (RequestedDestination::Amex, "AMEX"),
+            (RequestedDestination::Phlx, "PHLX"),
+            (RequestedDestination::Ise, "ISE"),
+            (RequestedDestination::Box, "BOX"),
+            (RequestedDestination::Nyse, "NYSE"),
+            (RequestedDestination::Nasdaq, "NASDAQ"),
+            (RequestedDestination::Bats, "BATS"),
+            (RequestedDestination::C2, "C2"),
+            (RequestedDestination::Auto, "AUTO"),
+        ]);
+    }
+
+    #[test]
+    fn stop_price_link_basis() {
+        assert_api_strings(&[
+            (StopPriceLinkBasis::Manual, "MANUAL"),
+            (StopPriceLinkBasis::Base, "BASE"),
+            (StopPriceLinkBasis::Trigger, "TRIGGER"),
+            (StopPriceLinkBasis::Last, "LAST"),
+            (StopPriceLinkBasis::Bid, "BID"),
+            (StopPriceLinkBasis::Ask, "ASK"),
+            (StopPriceLinkBasis::AskBid, "ASK_BID"),
+            (StopPriceLinkBasis::Mark, "MARK"),
+            (StopPriceLinkBasis::Average, "AVERAGE"),
+        ]);
+    }
+
+    #[test]
+    fn stop_price_link_type() {
+        assert_api_strings(&[
+            (StopPriceLinkType::Value, "VALUE"),
+            (StopPriceLinkType::Percent, "PERCENT"),
+            (StopPriceLinkType::Tick, "TICK"),
+        ]);
+    }
+
+    #[test]
+    fn stop_type() {
+        assert_api_strings(&[
+            (StopType::Standard, "STANDARD"),
+            (StopType::Bid, "BID"),
+            (StopType::Ask, "ASK"),
+            (StopType::Last, "LAST"),
+            (StopType::Mark, "MARK"),
+        ]);
+    }
+
+    #[test]
+    fn price_link_basis() {
+        assert_api_strings(&[
+            (PriceLinkBasis::Manual, "MANUAL"),
+            (PriceLinkBasis::Base, "BASE"),
+            (PriceLinkBasis::Trigger, "TRIGGER"),
+            (PriceLinkBasis::Last, "LAST"),
+            (PriceLinkBasis::Bid, "BID"),
+            (PriceLinkBasis::Ask, "ASK"),
+            (PriceLinkBasis::AskBid, "ASK_BID"),
+            (PriceLinkBasis::Mark, "MARK"),
+            (PriceLinkBasis::Average, "AVERAGE"),
+        ]);
+    }
+
+    #[test]
+    fn price_link_type() {
+        assert_api_strings(&[
+            (PriceLinkType::Value, "VALUE"),
+            (PriceLinkType::Percent, "PERCENT"),
+            (PriceLinkType::Tick, "TICK"),
+        ]);
+    }
+
+    #[test]
+    fn special_instruction() {
+        assert_api_strings(&[
+            (SpecialInstruction::AllOrNone, "ALL_OR_NONE"),
+            (SpecialInstruction::DoNotReduce, "DO_NOT_REDUCE"),
+            (
+                SpecialInstruction::AllOrNoneDoNotReduce,
+                "ALL_OR_NONE_DO_NOT_REDUCE",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn order_strategy_type() {
+        assert_api_strings(&[
+            (OrderStrategyType::Single, "SINGLE"),
+            (OrderStrategyType::Oco, "OCO"),
+            (OrderStrategyType::Trigger, "TRIGGER"),
+        ]);
+    }
+
+    #[test]
+    fn instruction() {
+        assert_api_strings(&[
+            (Instruction::Buy, "BUY"),
+            (Instruction::Sell, "SELL"),
+            (Instruction::BuyToCover, "BUY_TO_COVER"),
+            (Instruction::SellShort, "SELL_SHORT"),
+            (Instruction::BuyToOpen, "BUY_TO_OPEN"),
+            (Instruction::BuyToClose, "BUY_TO_CLOSE"),
+            (Instruction::SellToOpen, "SELL_TO_OPEN"),
+            (Instruction::SellToClose, "SELL_TO_CLOSE"),
+            (Instruction::Exchange, "EXCHANGE"),
+        ]);
+    }
+
+    #[test]
+    fn asset_type() {
+        assert_api_strings(&[
+            (AssetType::Equity, "EQUITY"),
+            (AssetType::Option, "OPTION"),
+            (AssetType::Index, "INDEX"),
+            (AssetType::MutualFund, "MUTUAL_FUND"),
+            (AssetType::CashEquivalent, "CASH_EQUIVALENT"),
+            (AssetType::FixedIncome, "FIXED_INCOME"),
+            (AssetType::Currency, "CURRENCY"),
+        ]);
+    }
+
+    #[test]
+    fn rejects_unknown_strings() {
+        assert!(serde_json::from_str::<OrderType>("\"LIMIT_ORDER\"").is_err());
+        assert!(serde_json::from_str::<Instruction>("\"buy\"").is_err());
+    }
+}
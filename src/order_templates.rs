@@ -1,4 +1,9 @@
 use crate::builders::OrderBuilder;
+use crate::order_enums::{
+    AssetType, ComplexOrderStrategyType, Duration, Instruction, OrderStrategyType, OrderType,
+    PriceLinkBasis, PriceLinkType, Session, StopPriceLinkBasis, StopPriceLinkType,
+};
+use crate::{OptionSymbol, PutCall, TDAClientError};
 
 /// Equity buy market order
 ///
@@ -6,11 +11,11 @@ use crate::builders::OrderBuilder;
 /// - duration: `DAY`
 pub fn equity_buy_market(symbol: &str, quantity: f64) -> OrderBuilder {
     OrderBuilder::new()
-        .set_order_strategy_type("SINGLE")
-        .set_order_type("MARKET")
-        .set_session("NORMAL")
-        .set_duration("DAY")
-        .add_order_leg("BUY", "EQUITY", symbol, quantity)
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Market)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .add_order_leg(Instruction::Buy, AssetType::Equity, symbol, quantity)
 }
 
 /// Equity buy limit order
@@ -19,12 +24,12 @@ pub fn equity_buy_market(symbol: &str, quantity: f64) -> OrderBuilder {
 /// - duration: `DAY`
 pub fn equity_buy_limit(symbol: &str, quantity: f64, price: f64) -> OrderBuilder {
     OrderBuilder::new()
-        .set_order_strategy_type("SINGLE")
-        .set_order_type("LIMIT")
-        .set_session("NORMAL")
-        .set_duration("DAY")
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Limit)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
         .set_price(price)
-        .add_order_leg("BUY", "EQUITY", symbol, quantity)
+        .add_order_leg(Instruction::Buy, AssetType::Equity, symbol, quantity)
 }
 
 /// Equity sell market order
@@ -33,11 +38,11 @@ pub fn equity_buy_limit(symbol: &str, quantity: f64, price: f64) -> OrderBuilder
 /// - duration: `DAY`
 pub fn equity_sell_market(symbol: &str, quantity: f64) -> OrderBuilder {
     OrderBuilder::new()
-        .set_order_strategy_type("SINGLE")
-        .set_order_type("MARKET")
-        .set_session("NORMAL")
-        .set_duration("DAY")
-        .add_order_leg("SELL", "EQUITY", symbol, quantity)
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Market)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .add_order_leg(Instruction::Sell, AssetType::Equity, symbol, quantity)
 }
 
 /// Equity sell limit order
@@ -46,12 +51,12 @@ pub fn equity_sell_market(symbol: &str, quantity: f64) -> OrderBuilder {
 /// - duration: `DAY`
 pub fn equity_sell_limit(symbol: &str, quantity: f64, price: f64) -> OrderBuilder {
     OrderBuilder::new()
-        .set_order_strategy_type("SINGLE")
-        .set_order_type("LIMIT")
-        .set_session("NORMAL")
-        .set_duration("DAY")
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Limit)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
         .set_price(price)
-        .add_order_leg("SELL", "EQUITY", symbol, quantity)
+        .add_order_leg(Instruction::Sell, AssetType::Equity, symbol, quantity)
 }
 
 /// Equity sell short market order
@@ -60,11 +65,11 @@ pub fn equity_sell_limit(symbol: &str, quantity: f64, price: f64) -> OrderBuilde
 /// - duration: `DAY`
 pub fn equity_sell_short_market(symbol: &str, quantity: f64) -> OrderBuilder {
     OrderBuilder::new()
-        .set_order_strategy_type("SINGLE")
-        .set_order_type("MARKET")
-        .set_session("NORMAL")
-        .set_duration("DAY")
-        .add_order_leg("SELL_SHORT", "EQUITY", symbol, quantity)
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Market)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .add_order_leg(Instruction::SellShort, AssetType::Equity, symbol, quantity)
 }
 
 /// Equity sell short limit order
@@ -73,12 +78,12 @@ pub fn equity_sell_short_market(symbol: &str, quantity: f64) -> OrderBuilder {
 /// - duration: `DAY`
 pub fn equity_sell_short_limit(symbol: &str, quantity: f64, price: f64) -> OrderBuilder {
     OrderBuilder::new()
-        .set_order_strategy_type("SINGLE")
-        .set_order_type("LIMIT")
-        .set_session("NORMAL")
-        .set_duration("DAY")
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Limit)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
         .set_price(price)
-        .add_order_leg("SELL_SHORT", "EQUITY", symbol, quantity)
+        .add_order_leg(Instruction::SellShort, AssetType::Equity, symbol, quantity)
 }
 
 /// Equity buy to cover market order
@@ -87,11 +92,11 @@ pub fn equity_sell_short_limit(symbol: &str, quantity: f64, price: f64) -> Order
 /// - duration: `DAY`
 pub fn equity_buy_to_cover_market(symbol: &str, quantity: f64) -> OrderBuilder {
     OrderBuilder::new()
-        .set_order_strategy_type("SINGLE")
-        .set_order_type("MARKET")
-        .set_session("NORMAL")
-        .set_duration("DAY")
-        .add_order_leg("BUY_TO_COVER", "EQUITY", symbol, quantity)
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Market)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .add_order_leg(Instruction::BuyToCover, AssetType::Equity, symbol, quantity)
 }
 
 /// Equity buy to cover limit order
@@ -100,18 +105,257 @@ pub fn equity_buy_to_cover_market(symbol: &str, quantity: f64) -> OrderBuilder {
 /// - duration: `DAY`
 pub fn equity_buy_to_cover_limit(symbol: &str, quantity: f64, price: f64) -> OrderBuilder {
     OrderBuilder::new()
-        .set_order_strategy_type("SINGLE")
-        .set_order_type("LIMIT")
-        .set_session("NORMAL")
-        .set_duration("DAY")
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Limit)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
         .set_price(price)
-        .add_order_leg("BUY_TO_COVER", "EQUITY", symbol, quantity)
+        .add_order_leg(Instruction::BuyToCover, AssetType::Equity, symbol, quantity)
+}
+
+/// Option buy to open market order
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn option_buy_to_open_market(symbol: &str, quantity: f64) -> OrderBuilder {
+    OrderBuilder::new()
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Market)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .add_order_leg(Instruction::BuyToOpen, AssetType::Option, symbol, quantity)
+}
+
+/// Option buy to open limit order
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn option_buy_to_open_limit(symbol: &str, quantity: f64, price: f64) -> OrderBuilder {
+    OrderBuilder::new()
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Limit)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .set_price(price)
+        .add_order_leg(Instruction::BuyToOpen, AssetType::Option, symbol, quantity)
+}
+
+/// Option sell to open market order
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn option_sell_to_open_market(symbol: &str, quantity: f64) -> OrderBuilder {
+    OrderBuilder::new()
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Market)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .add_order_leg(Instruction::SellToOpen, AssetType::Option, symbol, quantity)
+}
+
+/// Option sell to open limit order
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn option_sell_to_open_limit(symbol: &str, quantity: f64, price: f64) -> OrderBuilder {
+    OrderBuilder::new()
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Limit)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .set_price(price)
+        .add_order_leg(Instruction::SellToOpen, AssetType::Option, symbol, quantity)
+}
+
+/// Option buy to close market order
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn option_buy_to_close_market(symbol: &str, quantity: f64) -> OrderBuilder {
+    OrderBuilder::new()
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Market)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .add_order_leg(Instruction::BuyToClose, AssetType::Option, symbol, quantity)
+}
+
+/// Option buy to close limit order
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn option_buy_to_close_limit(symbol: &str, quantity: f64, price: f64) -> OrderBuilder {
+    OrderBuilder::new()
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Limit)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .set_price(price)
+        .add_order_leg(Instruction::BuyToClose, AssetType::Option, symbol, quantity)
+}
+
+/// Option sell to close market order
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn option_sell_to_close_market(symbol: &str, quantity: f64) -> OrderBuilder {
+    OrderBuilder::new()
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Market)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .add_order_leg(
+            Instruction::SellToClose,
+            AssetType::Option,
+            symbol,
+            quantity,
+        )
+}
+
+/// Option sell to close limit order
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn option_sell_to_close_limit(symbol: &str, quantity: f64, price: f64) -> OrderBuilder {
+    OrderBuilder::new()
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Limit)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .set_price(price)
+        .add_order_leg(
+            Instruction::SellToClose,
+            AssetType::Option,
+            symbol,
+            quantity,
+        )
+}
+
+/// Option exercise order. Exercises `quantity` contracts of a long option position
+///
+/// The `EXERCISE` order type is what makes this an exercise. TD Ameritrade takes the contracts as a `SELL_TO_CLOSE` leg because exercising closes the long position, but they're exercised into the underlying rather than sold
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn option_exercise(symbol: &str, quantity: f64) -> OrderBuilder {
+    OrderBuilder::new()
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Exercise)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .add_order_leg(
+            Instruction::SellToClose,
+            AssetType::Option,
+            symbol,
+            quantity,
+        )
+}
+
+/// Stop order. Becomes a market order once `stop_price` trades
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+/// - Option instructions (e.g. `SELL_TO_CLOSE`) use the `OPTION` asset type, other instructions use `EQUITY`
+pub fn stop(
+    instruction: Instruction,
+    symbol: &str,
+    quantity: f64,
+    stop_price: f64,
+) -> OrderBuilder {
+    OrderBuilder::new()
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Stop)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .set_stop_price(stop_price)
+        .add_order_leg(instruction, asset_type(instruction), symbol, quantity)
+}
+
+/// Stop limit order. Becomes a limit order at `price` once `stop_price` trades
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+/// - Option instructions (e.g. `SELL_TO_CLOSE`) use the `OPTION` asset type, other instructions use `EQUITY`
+pub fn stop_limit(
+    instruction: Instruction,
+    symbol: &str,
+    quantity: f64,
+    stop_price: f64,
+    price: f64,
+) -> OrderBuilder {
+    OrderBuilder::new()
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::StopLimit)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .set_stop_price(stop_price)
+        .set_price(price)
+        .add_order_leg(instruction, asset_type(instruction), symbol, quantity)
+}
+
+/// Trailing stop order. The stop price trails the last price by `stop_price_offset`, measured in `stop_price_link_type`
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+/// - stop_price_link_basis: `LAST`
+/// - Option instructions (e.g. `SELL_TO_CLOSE`) use the `OPTION` asset type, other instructions use `EQUITY`
+pub fn trailing_stop(
+    instruction: Instruction,
+    symbol: &str,
+    quantity: f64,
+    stop_price_offset: f64,
+    stop_price_link_type: StopPriceLinkType,
+) -> OrderBuilder {
+    OrderBuilder::new()
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::TrailingStop)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .set_stop_price_link_basis(StopPriceLinkBasis::Last)
+        .set_stop_price_link_type(stop_price_link_type)
+        .set_stop_price_offset(stop_price_offset)
+        .add_order_leg(instruction, asset_type(instruction), symbol, quantity)
+}
+
+/// Trailing stop limit order. Like `trailing_stop`, but becomes a limit order `limit_offset` away from the stop price, measured in the same units
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+/// - stop_price_link_basis: `LAST`
+/// - price_link_basis: `TRIGGER`, so the limit is offset from the stop price when it triggers
+/// - Option instructions (e.g. `SELL_TO_CLOSE`) use the `OPTION` asset type, other instructions use `EQUITY`
+pub fn trailing_stop_limit(
+    instruction: Instruction,
+    symbol: &str,
+    quantity: f64,
+    stop_price_offset: f64,
+    limit_offset: f64,
+    stop_price_link_type: StopPriceLinkType,
+) -> OrderBuilder {
+    let price_link_type = match stop_price_link_type {
+        StopPriceLinkType::Value => PriceLinkType::Value,
+        StopPriceLinkType::Percent => PriceLinkType::Percent,
+        StopPriceLinkType::Tick => PriceLinkType::Tick,
+    };
+
+    OrderBuilder::new()
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::TrailingStopLimit)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .set_stop_price_link_basis(StopPriceLinkBasis::Last)
+        .set_stop_price_link_type(stop_price_link_type)
+        .set_stop_price_offset(stop_price_offset)
+        .set_price_link_basis(PriceLinkBasis::Trigger)
+        .set_price_link_type(price_link_type)
+        .set_price(limit_offset)
+        .add_order_leg(instruction, asset_type(instruction), symbol, quantity)
 }
 
 /// OCO order
 pub fn one_cancels_other(order1: OrderBuilder, order2: OrderBuilder) -> OrderBuilder {
     OrderBuilder::new()
-        .set_order_strategy_type("OCO")
+        .set_order_strategy_type(OrderStrategyType::Oco)
         .add_child_order_strategy(order1)
         .add_child_order_strategy(order2)
 }
@@ -119,6 +363,1397 @@ pub fn one_cancels_other(order1: OrderBuilder, order2: OrderBuilder) -> OrderBui
 /// 1st Triggers 2nd Order
 pub fn first_triggers_second(order1: OrderBuilder, order2: OrderBuilder) -> OrderBuilder {
     order1
-        .set_order_strategy_type("TRIGGER")
+        .set_order_strategy_type(OrderStrategyType::Trigger)
         .add_child_order_strategy(order2)
 }
+
+/// Bracket order. The entry order triggers an OCO pair that exits at `take_profit_price` (limit) or `stop_loss_price` (stop)
+///
+/// Parameters
+/// - entry_instruction: `BUY` or `SELL_SHORT` for equities, `BUY_TO_OPEN` or `SELL_TO_OPEN` for options
+/// - entry_price: Limit price for the entry. `None` enters at market
+/// - take_profit_price: Limit price for the exit. Above `stop_loss_price` for long entries, below it for short entries
+/// - stop_loss_price: Stop price for the exit
+///
+/// The entry uses `DAY` and the exits use `GOOD_TILL_CANCEL`, all in the `NORMAL` session
+pub fn bracket(
+    entry_instruction: Instruction,
+    symbol: &str,
+    quantity: f64,
+    entry_price: Option<f64>,
+    take_profit_price: f64,
+    stop_loss_price: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    // Closing Instruction And Whether The Entry Opens A Long Position
+    let (exit_instruction, long) = match entry_instruction {
+        Instruction::Buy => (Instruction::Sell, true),
+        Instruction::SellShort => (Instruction::BuyToCover, false),
+        Instruction::BuyToOpen => (Instruction::SellToClose, true),
+        Instruction::SellToOpen => (Instruction::BuyToClose, false),
+        _ => {
+            return Err(TDAClientError::InvalidOrder(vec![format!(
+                "Bracket Entry Needs To Open A Position, Found {:?}",
+                entry_instruction
+            )]))
+        }
+    };
+
+    if (long && take_profit_price <= stop_loss_price)
+        || (!long && take_profit_price >= stop_loss_price)
+    {
+        return Err(TDAClientError::InvalidOrder(vec![format!(
+            "Bracket Take Profit Price {} Is On The Wrong Side Of Stop Loss Price {}",
+            take_profit_price, stop_loss_price
+        )]));
+    }
+
+    let entry = OrderBuilder::new()
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .add_order_leg(
+            entry_instruction,
+            asset_type(entry_instruction),
+            symbol,
+            quantity,
+        );
+
+    let entry = match entry_price {
+        Some(price) => entry.set_order_type(OrderType::Limit).set_price(price),
+        None => entry.set_order_type(OrderType::Market),
+    };
+
+    let take_profit = OrderBuilder::new()
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_order_type(OrderType::Limit)
+        .set_session(Session::Normal)
+        .set_duration(Duration::GoodTillCancel)
+        .set_price(take_profit_price)
+        .add_order_leg(
+            exit_instruction,
+            asset_type(exit_instruction),
+            symbol,
+            quantity,
+        );
+
+    let stop_loss = stop(exit_instruction, symbol, quantity, stop_loss_price)
+        .set_duration(Duration::GoodTillCancel);
+
+    Ok(first_triggers_second(
+        entry,
+        one_cancels_other(take_profit, stop_loss),
+    ))
+}
+
+/// Bull call vertical open order. Buys `long_call` and sells the higher strike `short_call`
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn bull_call_vertical_open(
+    long_call: &str,
+    short_call: &str,
+    quantity: f64,
+    net_debit: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    spread(
+        ComplexOrderStrategyType::Vertical,
+        OrderType::NetDebit,
+        net_debit,
+        &[
+            (Instruction::BuyToOpen, long_call, quantity),
+            (Instruction::SellToOpen, short_call, quantity),
+        ],
+        check_vertical(long_call, short_call, PutCall::Call),
+    )
+}
+
+/// Bull call vertical close order. Sells `long_call` and buys back the higher strike `short_call`
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn bull_call_vertical_close(
+    long_call: &str,
+    short_call: &str,
+    quantity: f64,
+    net_credit: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    spread(
+        ComplexOrderStrategyType::Vertical,
+        OrderType::NetCredit,
+        net_credit,
+        &[
+            (Instruction::SellToClose, long_call, quantity),
+            (Instruction::BuyToClose, short_call, quantity),
+        ],
+        check_vertical(long_call, short_call, PutCall::Call),
+    )
+}
+
+/// Bear call vertical open order. Sells `short_call` and buys the higher strike `long_call`
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn bear_call_vertical_open(
+    short_call: &str,
+    long_call: &str,
+    quantity: f64,
+    net_credit: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    spread(
+        ComplexOrderStrategyType::Vertical,
+        OrderType::NetCredit,
+        net_credit,
+        &[
+            (Instruction::SellToOpen, short_call, quantity),
+            (Instruction::BuyToOpen, long_call, quantity),
+        ],
+        check_vertical(short_call, long_call, PutCall::Call),
+    )
+}
+
+/// Bear call vertical close order. Buys back `short_call` and sells the higher strike `long_call`
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn bear_call_vertical_close(
+    short_call: &str,
+    long_call: &str,
+    quantity: f64,
+    net_debit: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    spread(
+        ComplexOrderStrategyType::Vertical,
+        OrderType::NetDebit,
+        net_debit,
+        &[
+            (Instruction::BuyToClose, short_call, quantity),
+            (Instruction::SellToClose, long_call, quantity),
+        ],
+        check_vertical(short_call, long_call, PutCall::Call),
+    )
+}
+
+/// Bull put vertical open order. Buys `long_put` and sells the higher strike `short_put`
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn bull_put_vertical_open(
+    long_put: &str,
+    short_put: &str,
+    quantity: f64,
+    net_credit: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    spread(
+        ComplexOrderStrategyType::Vertical,
+        OrderType::NetCredit,
+        net_credit,
+        &[
+            (Instruction::BuyToOpen, long_put, quantity),
+            (Instruction::SellToOpen, short_put, quantity),
+        ],
+        check_vertical(long_put, short_put, PutCall::Put),
+    )
+}
+
+/// Bull put vertical close order. Sells `long_put` and buys back the higher strike `short_put`
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn bull_put_vertical_close(
+    long_put: &str,
+    short_put: &str,
+    quantity: f64,
+    net_debit: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    spread(
+        ComplexOrderStrategyType::Vertical,
+        OrderType::NetDebit,
+        net_debit,
+        &[
+            (Instruction::SellToClose, long_put, quantity),
+            (Instruction::BuyToClose, short_put, quantity),
+        ],
+        check_vertical(long_put, short_put, PutCall::Put),
+    )
+}
+
+/// Bear put vertical open order. Sells `short_put` and buys the higher strike `long_put`
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn bear_put_vertical_open(
+    short_put: &str,
+    long_put: &str,
+    quantity: f64,
+    net_debit: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    spread(
+        ComplexOrderStrategyType::Vertical,
+        OrderType::NetDebit,
+        net_debit,
+        &[
+            (Instruction::SellToOpen, short_put, quantity),
+            (Instruction::BuyToOpen, long_put, quantity),
+        ],
+        check_vertical(short_put, long_put, PutCall::Put),
+    )
+}
+
+/// Bear put vertical close order. Buys back `short_put` and sells the higher strike `long_put`
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn bear_put_vertical_close(
+    short_put: &str,
+    long_put: &str,
+    quantity: f64,
+    net_credit: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    spread(
+        ComplexOrderStrategyType::Vertical,
+        OrderType::NetCredit,
+        net_credit,
+        &[
+            (Instruction::BuyToClose, short_put, quantity),
+            (Instruction::SellToClose, long_put, quantity),
+        ],
+        check_vertical(short_put, long_put, PutCall::Put),
+    )
+}
+
+/// Calendar spread open order. Sells `near_option` and buys `far_option` with the same strike and a later expiration
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn calendar_spread_open(
+    near_option: &str,
+    far_option: &str,
+    quantity: f64,
+    net_debit: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    let mut violations = Vec::new();
+
+    if let Some([near, far]) = parse_options([near_option, far_option], &mut violations) {
+        check_later_expiration(&near, &far, &mut violations);
+        check_same_put_call(&[&near, &far], &mut violations);
+
+        if near.strike != far.strike {
+            violations.push(format!(
+                "Calendar Spread Legs Need The Same Strike, Found {} And {}",
+                near.strike, far.strike
+            ));
+        }
+    }
+
+    spread(
+        ComplexOrderStrategyType::Calendar,
+        OrderType::NetDebit,
+        net_debit,
+        &[
+            (Instruction::SellToOpen, near_option, quantity),
+            (Instruction::BuyToOpen, far_option, quantity),
+        ],
+        violations,
+    )
+}
+
+/// Diagonal spread open order. Sells `near_option` and buys `far_option` with a different strike and a later expiration
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn diagonal_spread_open(
+    near_option: &str,
+    far_option: &str,
+    quantity: f64,
+    net_debit: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    let mut violations = Vec::new();
+
+    if let Some([near, far]) = parse_options([near_option, far_option], &mut violations) {
+        check_later_expiration(&near, &far, &mut violations);
+        check_same_put_call(&[&near, &far], &mut violations);
+
+        if near.strike == far.strike {
+            violations.push(format!(
+                "Diagonal Spread Legs Need Different Strikes, Found {} For Both",
+                near.strike
+            ));
+        }
+    }
+
+    spread(
+        ComplexOrderStrategyType::Diagonal,
+        OrderType::NetDebit,
+        net_debit,
+        &[
+            (Instruction::SellToOpen, near_option, quantity),
+            (Instruction::BuyToOpen, far_option, quantity),
+        ],
+        violations,
+    )
+}
+
+/// Long straddle order. Buys `call` and `put` with the same strike and expiration
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn long_straddle(
+    call: &str,
+    put: &str,
+    quantity: f64,
+    net_debit: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    spread(
+        ComplexOrderStrategyType::Straddle,
+        OrderType::NetDebit,
+        net_debit,
+        &[
+            (Instruction::BuyToOpen, call, quantity),
+            (Instruction::BuyToOpen, put, quantity),
+        ],
+        check_straddle(call, put, true),
+    )
+}
+
+/// Short straddle order. Sells `call` and `put` with the same strike and expiration
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn short_straddle(
+    call: &str,
+    put: &str,
+    quantity: f64,
+    net_credit: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    spread(
+        ComplexOrderStrategyType::Straddle,
+        OrderType::NetCredit,
+        net_credit,
+        &[
+            (Instruction::SellToOpen, call, quantity),
+            (Instruction::SellToOpen, put, quantity),
+        ],
+        check_straddle(call, put, true),
+    )
+}
+
+/// Long strangle order. Buys `call` and the lower strike `put` with the same expiration
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn long_strangle(
+    call: &str,
+    put: &str,
+    quantity: f64,
+    net_debit: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    spread(
+        ComplexOrderStrategyType::Strangle,
+        OrderType::NetDebit,
+        net_debit,
+        &[
+            (Instruction::BuyToOpen, call, quantity),
+            (Instruction::BuyToOpen, put, quantity),
+        ],
+        check_straddle(call, put, false),
+    )
+}
+
+/// Short strangle order. Sells `call` and the lower strike `put` with the same expiration
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn short_strangle(
+    call: &str,
+    put: &str,
+    quantity: f64,
+    net_credit: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    spread(
+        ComplexOrderStrategyType::Strangle,
+        OrderType::NetCredit,
+        net_credit,
+        &[
+            (Instruction::SellToOpen, call, quantity),
+            (Instruction::SellToOpen, put, quantity),
+        ],
+        check_straddle(call, put, false),
+    )
+}
+
+/// Long butterfly order. Buys `lower_option`, sells twice the quantity of `middle_option`, and buys `upper_option`
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+/// - The strikes must be evenly spaced and the legs must all be calls or all be puts
+pub fn long_butterfly(
+    lower_option: &str,
+    middle_option: &str,
+    upper_option: &str,
+    quantity: f64,
+    net_debit: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    let mut violations = Vec::new();
+
+    if let Some([lower, middle, upper]) =
+        parse_options([lower_option, middle_option, upper_option], &mut violations)
+    {
+        check_same_expiration(&[&lower, &middle, &upper], &mut violations);
+        check_same_put_call(&[&lower, &middle, &upper], &mut violations);
+        check_ascending_strikes(&[&lower, &middle, &upper], &mut violations);
+
+        // Compare In Thousandths So Decimal Strikes Like 400.1/400.2/400.3 Aren't Thrown Off By f64 Rounding
+        let [lower_strike, middle_strike, upper_strike] =
+            [&lower, &middle, &upper].map(|option_leg| (option_leg.strike * 1000.0).round() as i64);
+
+        if middle_strike - lower_strike != upper_strike - middle_strike {
+            violations.push(format!(
+                "Butterfly Strikes Need Even Spacing, Found {}, {}, And {}",
+                lower.strike, middle.strike, upper.strike
+            ));
+        }
+    }
+
+    spread(
+        ComplexOrderStrategyType::Butterfly,
+        OrderType::NetDebit,
+        net_debit,
+        &[
+            (Instruction::BuyToOpen, lower_option, quantity),
+            (Instruction::SellToOpen, middle_option, quantity * 2.0),
+            (Instruction::BuyToOpen, upper_option, quantity),
+        ],
+        violations,
+    )
+}
+
+/// Short iron condor order. Buys `long_put`, sells `short_put`, sells `short_call`, and buys `long_call`
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+/// - The strikes must be in that order from lowest to highest
+pub fn short_iron_condor(
+    long_put: &str,
+    short_put: &str,
+    short_call: &str,
+    long_call: &str,
+    quantity: f64,
+    net_credit: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    let mut violations = Vec::new();
+
+    if let Some([lp, sp, sc, lc]) = parse_options(
+        [long_put, short_put, short_call, long_call],
+        &mut violations,
+    ) {
+        check_same_expiration(&[&lp, &sp, &sc, &lc], &mut violations);
+        check_same_put_call(&[&lp, &sp], &mut violations);
+        check_same_put_call(&[&sc, &lc], &mut violations);
+        check_put_call(&lp, PutCall::Put, &mut violations);
+        check_put_call(&sc, PutCall::Call, &mut violations);
+        check_ascending_strikes(&[&lp, &sp, &sc, &lc], &mut violations);
+    }
+
+    spread(
+        ComplexOrderStrategyType::IronCondor,
+        OrderType::NetCredit,
+        net_credit,
+        &[
+            (Instruction::BuyToOpen, long_put, quantity),
+            (Instruction::SellToOpen, short_put, quantity),
+            (Instruction::SellToOpen, short_call, quantity),
+            (Instruction::BuyToOpen, long_call, quantity),
+        ],
+        violations,
+    )
+}
+
+/// Collar with stock order. Buys 100 shares of `stock` per contract, buys `long_put`, and sells the higher strike `short_call`
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+pub fn collar_with_stock(
+    stock: &str,
+    long_put: &str,
+    short_call: &str,
+    quantity: f64,
+    net_debit: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    let mut violations = Vec::new();
+
+    if let Some([put, call]) = parse_options([long_put, short_call], &mut violations) {
+        check_same_expiration(&[&put, &call], &mut violations);
+        check_put_call(&put, PutCall::Put, &mut violations);
+        check_put_call(&call, PutCall::Call, &mut violations);
+        check_ascending_strikes(&[&put, &call], &mut violations);
+
+        if put.underlying != stock {
+            violations.push(format!(
+                "Collar Options Are On {}, Not {}",
+                put.underlying, stock
+            ));
+        }
+    }
+
+    spread(
+        ComplexOrderStrategyType::CollarWithStock,
+        OrderType::NetDebit,
+        net_debit,
+        &[
+            (Instruction::Buy, stock, quantity * 100.0),
+            (Instruction::BuyToOpen, long_put, quantity),
+            (Instruction::SellToOpen, short_call, quantity),
+        ],
+        violations,
+    )
+}
+
+/// Vertical roll order. Closes the `close_long`/`close_short` vertical and opens the `open_long`/`open_short` vertical
+///
+/// - session: `NORMAL`
+/// - duration: `DAY`
+/// - order_type: `NET_DEBIT` or `NET_CREDIT`
+pub fn vertical_roll(
+    close_long: &str,
+    close_short: &str,
+    open_long: &str,
+    open_short: &str,
+    quantity: f64,
+    order_type: OrderType,
+    price: f64,
+) -> Result<OrderBuilder, TDAClientError> {
+    let mut violations = Vec::new();
+
+    if !matches!(order_type, OrderType::NetDebit | OrderType::NetCredit) {
+        violations.push(format!(
+            "Vertical Roll Needs A NET_DEBIT Or NET_CREDIT Order Type, Found {:?}",
+            order_type
+        ));
+    }
+
+    if let Some([cl, cs, ol, os]) = parse_options(
+        [close_long, close_short, open_long, open_short],
+        &mut violations,
+    ) {
+        check_same_expiration(&[&cl, &cs], &mut violations);
+        check_same_expiration(&[&ol, &os], &mut violations);
+        check_same_put_call(&[&cl, &cs, &ol, &os], &mut violations);
+    }
+
+    spread(
+        ComplexOrderStrategyType::VerticalRoll,
+        order_type,
+        price,
+        &[
+            (Instruction::SellToClose, close_long, quantity),
+            (Instruction::BuyToClose, close_short, quantity),
+            (Instruction::BuyToOpen, open_long, quantity),
+            (Instruction::SellToOpen, open_short, quantity),
+        ],
+        violations,
+    )
+}
+
+// Build Spread Order, Or Return Every Violation Found In Its Legs
+fn spread(
+    complex_order_strategy_type: ComplexOrderStrategyType,
+    order_type: OrderType,
+    price: f64,
+    legs: &[(Instruction, &str, f64)],
+    mut violations: Vec<String>,
+) -> Result<OrderBuilder, TDAClientError> {
+    let mut order = OrderBuilder::new()
+        .set_order_strategy_type(OrderStrategyType::Single)
+        .set_complex_order_strategy_type(complex_order_strategy_type)
+        .set_order_type(order_type)
+        .set_session(Session::Normal)
+        .set_duration(Duration::Day)
+        .set_price(price);
+
+    for (instruction, symbol, quantity) in legs {
+        if *quantity <= 0.0 || quantity.fract() != 0.0 {
+            violations.push(format!(
+                "{} Quantity Must Be A Positive Whole Number, Found {}",
+                symbol, quantity
+            ));
+        }
+
+        order = order.add_order_leg(*instruction, asset_type(*instruction), symbol, *quantity);
+    }
+
+    if !violations.is_empty() {
+        return Err(TDAClientError::InvalidOrder(violations));
+    }
+
+    Ok(order)
+}
+
+// Option Legs Use The Open/Close Instructions, Equity Legs Use The Rest
+fn asset_type(instruction: Instruction) -> AssetType {
+    match instruction {
+        Instruction::BuyToOpen
+        | Instruction::BuyToClose
+        | Instruction::SellToOpen
+        | Instruction::SellToClose => AssetType::Option,
+        _ => AssetType::Equity,
+    }
+}
+
+// Check Vertical Legs Share An Expiration And Option Type, With Strikes Lowest First
+fn check_vertical(lower_option: &str, upper_option: &str, put_call: PutCall) -> Vec<String> {
+    let mut violations = Vec::new();
+
+    if let Some([lower, upper]) = parse_options([lower_option, upper_option], &mut violations) {
+        check_same_expiration(&[&lower, &upper], &mut violations);
+        check_put_call(&lower, put_call, &mut violations);
+        check_put_call(&upper, put_call, &mut violations);
+        check_ascending_strikes(&[&lower, &upper], &mut violations);
+    }
+
+    violations
+}
+
+// Check Straddle/Strangle Legs Are A Call And A Put With The Same Expiration
+fn check_straddle(call_option: &str, put_option: &str, same_strike: bool) -> Vec<String> {
+    let mut violations = Vec::new();
+
+    if let Some([call, put]) = parse_options([call_option, put_option], &mut violations) {
+        check_same_expiration(&[&call, &put], &mut violations);
+        check_put_call(&call, PutCall::Call, &mut violations);
+        check_put_call(&put, PutCall::Put, &mut violations);
+
+        if same_strike && call.strike != put.strike {
+            violations.push(format!(
+                "Straddle Legs Need The Same Strike, Found {} And {}",
+                call.strike, put.strike
+            ));
+        }
+
+        if !same_strike {
+            check_ascending_strikes(&[&put, &call], &mut violations);
+        }
+    }
+
+    violations
+}
+
+// Parse Every Leg And Check They Share An Underlying. Returns None If Any Leg Doesn't Parse
+fn parse_options<const N: usize>(
+    symbols: [&str; N],
+    violations: &mut Vec<String>,
+) -> Option<[OptionSymbol; N]> {
+    let mut option_legs = Vec::with_capacity(N);
+
+    for symbol in symbols {
+        match symbol.parse::<OptionSymbol>() {
+            Ok(option_leg) => option_legs.push(option_leg),
+            Err(_) => violations.push(format!(
+                "{} Isn't A TDA Option Symbol (e.g. AAPL_011924C150)",
+                symbol
+            )),
+        }
+    }
+
+    let option_legs: [OptionSymbol; N] = option_legs.try_into().ok()?;
+
+    if option_legs
+        .iter()
+        .any(|option_leg| option_leg.underlying != option_legs[0].underlying)
+    {
+        violations.push(format!(
+            "Legs Need The Same Underlying, Found {}",
+            join_symbols(&option_legs.iter().collect::<Vec<&OptionSymbol>>())
+        ));
+    }
+
+    Some(option_legs)
+}
+
+fn check_same_expiration(option_legs: &[&OptionSymbol], violations: &mut Vec<String>) {
+    if option_legs
+        .iter()
+        .any(|option_leg| option_leg.expiration != option_legs[0].expiration)
+    {
+        violations.push(format!(
+            "Legs Need The Same Expiration, Found {}",
+            join_symbols(option_legs)
+        ));
+    }
+}
+
+fn check_later_expiration(near: &OptionSymbol, far: &OptionSymbol, violations: &mut Vec<String>) {
+    if near.expiration >= far.expiration {
+        violations.push(format!("{} Needs To Expire After {}", far, near));
+    }
+}
+
+fn check_same_put_call(option_legs: &[&OptionSymbol], violations: &mut Vec<String>) {
+    if option_legs
+        .iter()
+        .any(|option_leg| option_leg.put_call != option_legs[0].put_call)
+    {
+        violations.push(format!(
+            "Legs Need To All Be Calls Or All Be Puts, Found {}",
+            join_symbols(option_legs)
+        ));
+    }
+}
+
+fn check_put_call(option_leg: &OptionSymbol, put_call: PutCall, violations: &mut Vec<String>) {
+    if option_leg.put_call != put_call {
+        violations.push(format!("{} Needs To Be A {:?}", option_leg, put_call));
+    }
+}
+
+fn check_ascending_strikes(option_legs: &[&OptionSymbol], violations: &mut Vec<String>) {
+    if option_legs
+        .windows(2)
+        .any(|pair| pair[0].strike >= pair[1].strike)
+    {
+        violations.push(format!(
+            "Leg Strikes Need To Increase In Order, Found {}",
+            join_symbols(option_legs)
+        ));
+    }
+}
+
+fn join_symbols(option_legs: &[&OptionSymbol]) -> String {
+    option_legs
+        .iter()
+        .map(|option_leg| option_leg.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    // The Order JSON Sent To The API
+    fn order_json(order_builder: OrderBuilder) -> Value {
+        serde_json::from_str(&order_builder.build().unwrap()).unwrap()
+    }
+
+    // The (Instruction, Symbol, Quantity) Of Every Leg
+    fn legs(order: &Value) -> Vec<(String, String, f64)> {
+        order["orderLegCollection"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|leg| {
+                (
+                    leg["instruction"].as_str().unwrap().to_string(),
+                    leg["instrument"]["symbol"].as_str().unwrap().to_string(),
+                    leg["quantity"].as_f64().unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    fn leg(instruction: &str, symbol: &str, quantity: f64) -> (String, String, f64) {
+        (instruction.to_string(), symbol.to_string(), quantity)
+    }
+
+    fn violations(result: Result<OrderBuilder, TDAClientError>) -> Vec<String> {
+        match result {
+            Err(TDAClientError::InvalidOrder(violations)) => violations,
+            other => panic!("Expected InvalidOrder, Found {:?}", other),
+        }
+    }
+
+    fn assert_violation(result: Result<OrderBuilder, TDAClientError>, expected: &str) {
+        let violations = violations(result);
+        assert!(
+            violations
+                .iter()
+                .any(|violation| violation.contains(expected)),
+            "{:?} Doesn't Contain {:?}",
+            violations,
+            expected
+        );
+    }
+
+    #[test]
+    fn builds_single_leg_option_orders() {
+        let option_leg = |instruction: &str| {
+            json!([{
+                "instruction": instruction,
+                "instrument": { "assetType": "OPTION", "symbol": "AAPL_011924C150" },
+                "quantity": 2.0
+            }])
+        };
+
+        assert_eq!(
+            order_json(option_buy_to_open_limit("AAPL_011924C150", 2.0, 1.5)),
+            json!({
+                "session": "NORMAL",
+                "duration": "DAY",
+                "orderType": "LIMIT",
+                "price": 1.5,
+                "orderLegCollection": option_leg("BUY_TO_OPEN"),
+                "orderStrategyType": "SINGLE"
+            })
+        );
+
+        assert_eq!(
+            order_json(option_sell_to_close_market("AAPL_011924C150", 2.0)),
+            json!({
+                "session": "NORMAL",
+                "duration": "DAY",
+                "orderType": "MARKET",
+                "orderLegCollection": option_leg("SELL_TO_CLOSE"),
+                "orderStrategyType": "SINGLE"
+            })
+        );
+
+        let templates: [(OrderBuilder, &str, &str); 6] = [
+            (
+                option_buy_to_open_market("AAPL_011924C150", 2.0),
+                "MARKET",
+                "BUY_TO_OPEN",
+            ),
+            (
+                option_sell_to_open_market("AAPL_011924C150", 2.0),
+                "MARKET",
+                "SELL_TO_OPEN",
+            ),
+            (
+                option_sell_to_open_limit("AAPL_011924C150", 2.0, 1.5),
+                "LIMIT",
+                "SELL_TO_OPEN",
+            ),
+            (
+                option_buy_to_close_market("AAPL_011924C150", 2.0),
+                "MARKET",
+                "BUY_TO_CLOSE",
+            ),
+            (
+                option_buy_to_close_limit("AAPL_011924C150", 2.0, 1.5),
+                "LIMIT",
+                "BUY_TO_CLOSE",
+            ),
+            (
+                option_sell_to_close_limit("AAPL_011924C150", 2.0, 1.5),
+                "LIMIT",
+                "SELL_TO_CLOSE",
+            ),
+        ];
+
+        for (template, order_type, instruction) in templates {
+            let order = order_json(template);
+            assert_eq!(order["orderType"], order_type);
+            assert_eq!(order["orderLegCollection"], option_leg(instruction));
+        }
+    }
+
+    #[test]
+    fn builds_option_exercise() {
+        assert_eq!(
+            order_json(option_exercise("AAPL_011924C150", 2.0)),
+            json!({
+                "session": "NORMAL",
+                "duration": "DAY",
+                "orderType": "EXERCISE",
+                "orderLegCollection": [{
+                    "instruction": "SELL_TO_CLOSE",
+                    "instrument": { "assetType": "OPTION", "symbol": "AAPL_011924C150" },
+                    "quantity": 2.0
+                }],
+                "orderStrategyType": "SINGLE"
+            })
+        );
+    }
+
+    #[test]
+    fn builds_stop_orders() {
+        let order = order_json(stop(Instruction::Sell, "AAPL", 10.0, 145.0));
+        assert_eq!(order["orderType"], "STOP");
+        assert_eq!(order["stopPrice"], 145.0);
+        assert!(order.get("price").is_none());
+        assert_eq!(
+            order["orderLegCollection"][0]["instrument"]["assetType"],
+            "EQUITY"
+        );
+        assert_eq!(legs(&order), vec![leg("SELL", "AAPL", 10.0)]);
+
+        let order = order_json(stop_limit(
+            Instruction::SellToClose,
+            "AAPL_011924C150",
+            1.0,
+            2.0,
+            1.9,
+        ));
+        assert_eq!(order["orderType"], "STOP_LIMIT");
+        assert_eq!(order["stopPrice"], 2.0);
+        assert_eq!(order["price"], 1.9);
+        assert_eq!(
+            order["orderLegCollection"][0]["instrument"]["assetType"],
+            "OPTION"
+        );
+    }
+
+    #[test]
+    fn builds_trailing_stop_orders() {
+        let order = order_json(trailing_stop(
+            Instruction::Sell,
+            "AAPL",
+            10.0,
+            -2.5,
+            StopPriceLinkType::Value,
+        ));
+        assert_eq!(order["orderType"], "TRAILING_STOP");
+        assert_eq!(order["stopPriceLinkBasis"], "LAST");
+        assert_eq!(order["stopPriceLinkType"], "VALUE");
+        assert_eq!(order["stopPriceOffset"], -2.5);
+        assert!(order.get("price").is_none());
+
+        // The Limit Is Offset From The Stop Price, Not The Last Price
+        let order = order_json(trailing_stop_limit(
+            Instruction::Sell,
+            "AAPL",
+            10.0,
+            -5.0,
+            -1.0,
+            StopPriceLinkType::Percent,
+        ));
+        assert_eq!(order["orderType"], "TRAILING_STOP_LIMIT");
+        assert_eq!(order["stopPriceLinkBasis"], "LAST");
+        assert_eq!(order["stopPriceLinkType"], "PERCENT");
+        assert_eq!(order["stopPriceOffset"], -5.0);
+        assert_eq!(order["priceLinkBasis"], "TRIGGER");
+        assert_eq!(order["priceLinkType"], "PERCENT");
+        assert_eq!(order["price"], -1.0);
+    }
+
+    #[test]
+    fn builds_bracket() {
+        let order =
+            order_json(bracket(Instruction::Buy, "AAPL", 10.0, Some(150.0), 160.0, 145.0).unwrap());
+        assert_eq!(order["orderStrategyType"], "TRIGGER");
+        assert_eq!(order["orderType"], "LIMIT");
+        assert_eq!(order["duration"], "DAY");
+        assert_eq!(order["price"], 150.0);
+        assert_eq!(legs(&order), vec![leg("BUY", "AAPL", 10.0)]);
+
+        let oco = &order["childOrderStrategies"][0];
+        assert_eq!(oco["orderStrategyType"], "OCO");
+
+        let take_profit = &oco["childOrderStrategies"][0];
+        assert_eq!(take_profit["orderType"], "LIMIT");
+        assert_eq!(take_profit["duration"], "GOOD_TILL_CANCEL");
+        assert_eq!(take_profit["price"], 160.0);
+        assert_eq!(legs(take_profit), vec![leg("SELL", "AAPL", 10.0)]);
+
+        let stop_loss = &oco["childOrderStrategies"][1];
+        assert_eq!(stop_loss["orderType"], "STOP");
+        assert_eq!(stop_loss["duration"], "GOOD_TILL_CANCEL");
+        assert_eq!(stop_loss["stopPrice"], 145.0);
+        assert_eq!(legs(stop_loss), vec![leg("SELL", "AAPL", 10.0)]);
+
+        // Short Option Entry At Market Exits With BUY_TO_CLOSE
+        let order = order_json(
+            bracket(
+                Instruction::SellToOpen,
+                "AAPL_011924C150",
+                1.0,
+                None,
+                1.0,
+                4.0,
+            )
+            .unwrap(),
+        );
+        assert_eq!(order["orderType"], "MARKET");
+        assert!(order.get("price").is_none());
+        assert_eq!(
+            legs(&order["childOrderStrategies"][0]["childOrderStrategies"][1]),
+            vec![leg("BUY_TO_CLOSE", "AAPL_011924C150", 1.0)]
+        );
+    }
+
+    #[test]
+    fn rejects_bad_brackets() {
+        assert_violation(
+            bracket(Instruction::Sell, "AAPL", 10.0, None, 160.0, 145.0),
+            "Bracket Entry Needs To Open A Position, Found Sell",
+        );
+        assert_violation(
+            bracket(Instruction::Buy, "AAPL", 10.0, None, 145.0, 160.0),
+            "Bracket Take Profit Price 145 Is On The Wrong Side Of Stop Loss Price 160",
+        );
+        assert_violation(
+            bracket(Instruction::SellShort, "AAPL", 10.0, None, 160.0, 145.0),
+            "Bracket Take Profit Price 160 Is On The Wrong Side Of Stop Loss Price 145",
+        );
+    }
+
+    #[test]
+    fn builds_verticals() {
+        let order = order_json(
+            bull_call_vertical_open("SPY_011924C400", "SPY_011924C410", 2.0, 4.5).unwrap(),
+        );
+        assert_eq!(order["complexOrderStrategyType"], "VERTICAL");
+        assert_eq!(order["orderType"], "NET_DEBIT");
+        assert_eq!(order["price"], 4.5);
+        assert_eq!(
+            order["orderLegCollection"][0]["instrument"]["assetType"],
+            "OPTION"
+        );
+        assert_eq!(
+            legs(&order),
+            vec![
+                leg("BUY_TO_OPEN", "SPY_011924C400", 2.0),
+                leg("SELL_TO_OPEN", "SPY_011924C410", 2.0),
+            ]
+        );
+
+        let order = order_json(
+            bull_put_vertical_close("SPY_011924P390", "SPY_011924P400", 1.0, 1.25).unwrap(),
+        );
+        assert_eq!(order["orderType"], "NET_DEBIT");
+        assert_eq!(
+            legs(&order),
+            vec![
+                leg("SELL_TO_CLOSE", "SPY_011924P390", 1.0),
+                leg("BUY_TO_CLOSE", "SPY_011924P400", 1.0),
+            ]
+        );
+
+        let order = order_json(
+            bear_call_vertical_open("SPY_011924C400", "SPY_011924C410", 1.0, 3.0).unwrap(),
+        );
+        assert_eq!(order["orderType"], "NET_CREDIT");
+        assert_eq!(
+            legs(&order),
+            vec![
+                leg("SELL_TO_OPEN", "SPY_011924C400", 1.0),
+                leg("BUY_TO_OPEN", "SPY_011924C410", 1.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_bad_verticals() {
+        assert_violation(
+            bull_call_vertical_open("SPY_011924C410", "SPY_011924C400", 1.0, 4.5),
+            "Leg Strikes Need To Increase In Order",
+        );
+        assert_violation(
+            bear_put_vertical_open("SPY_011924P390", "SPY_021624P400", 1.0, 4.5),
+            "Legs Need The Same Expiration",
+        );
+        assert_violation(
+            bull_put_vertical_open("SPY_011924C390", "SPY_011924P400", 1.0, 1.0),
+            "SPY_011924C390 Needs To Be A Put",
+        );
+        assert_violation(
+            bull_call_vertical_open("SPY_011924C400", "QQQ_011924C410", 1.0, 4.5),
+            "Legs Need The Same Underlying",
+        );
+        assert_violation(
+            bull_call_vertical_open("SPY", "SPY_011924C410", 1.0, 4.5),
+            "SPY Isn't A TDA Option Symbol",
+        );
+        assert_violation(
+            bull_call_vertical_open("SPY_011924C400", "SPY_011924C410", 1.5, 4.5),
+            "Quantity Must Be A Positive Whole Number, Found 1.5",
+        );
+    }
+
+    #[test]
+    fn builds_calendar_and_diagonal() {
+        let order =
+            order_json(calendar_spread_open("SPY_011924C400", "SPY_021624C400", 1.0, 2.0).unwrap());
+        assert_eq!(order["complexOrderStrategyType"], "CALENDAR");
+        assert_eq!(
+            legs(&order),
+            vec![
+                leg("SELL_TO_OPEN", "SPY_011924C400", 1.0),
+                leg("BUY_TO_OPEN", "SPY_021624C400", 1.0),
+            ]
+        );
+
+        let order =
+            order_json(diagonal_spread_open("SPY_011924C400", "SPY_021624C410", 1.0, 1.0).unwrap());
+        assert_eq!(order["complexOrderStrategyType"], "DIAGONAL");
+        assert_eq!(
+            legs(&order),
+            vec![
+                leg("SELL_TO_OPEN", "SPY_011924C400", 1.0),
+                leg("BUY_TO_OPEN", "SPY_021624C410", 1.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_bad_calendar_and_diagonal() {
+        assert_violation(
+            calendar_spread_open("SPY_021624C400", "SPY_011924C400", 1.0, 2.0),
+            "SPY_011924C400 Needs To Expire After SPY_021624C400",
+        );
+        assert_violation(
+            calendar_spread_open("SPY_011924C400", "SPY_021624C410", 1.0, 2.0),
+            "Calendar Spread Legs Need The Same Strike",
+        );
+        assert_violation(
+            calendar_spread_open("SPY_011924C400", "SPY_021624P400", 1.0, 2.0),
+            "Legs Need To All Be Calls Or All Be Puts",
+        );
+        assert_violation(
+            diagonal_spread_open("SPY_011924C400", "SPY_021624C400", 1.0, 1.0),
+            "Diagonal Spread Legs Need Different Strikes",
+        );
+    }
+
+    #[test]
+    fn builds_straddles_and_strangles() {
+        let order =
+            order_json(long_straddle("SPY_011924C400", "SPY_011924P400", 1.0, 12.0).unwrap());
+        assert_eq!(order["complexOrderStrategyType"], "STRADDLE");
+        assert_eq!(order["orderType"], "NET_DEBIT");
+        assert_eq!(
+            legs(&order),
+            vec![
+                leg("BUY_TO_OPEN", "SPY_011924C400", 1.0),
+                leg("BUY_TO_OPEN", "SPY_011924P400", 1.0),
+            ]
+        );
+
+        let order =
+            order_json(short_strangle("SPY_011924C410", "SPY_011924P390", 1.0, 6.0).unwrap());
+        assert_eq!(order["complexOrderStrategyType"], "STRANGLE");
+        assert_eq!(order["orderType"], "NET_CREDIT");
+        assert_eq!(
+            legs(&order),
+            vec![
+                leg("SELL_TO_OPEN", "SPY_011924C410", 1.0),
+                leg("SELL_TO_OPEN", "SPY_011924P390", 1.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_bad_straddles_and_strangles() {
+        assert_violation(
+            short_straddle("SPY_011924C400", "SPY_011924P410", 1.0, 12.0),
+            "Straddle Legs Need The Same Strike",
+        );
+        assert_violation(
+            long_straddle("SPY_011924P400", "SPY_011924C400", 1.0, 12.0),
+            "SPY_011924P400 Needs To Be A Call",
+        );
+        assert_violation(
+            long_strangle("SPY_011924C390", "SPY_011924P410", 1.0, 6.0),
+            "Leg Strikes Need To Increase In Order",
+        );
+    }
+
+    #[test]
+    fn builds_butterfly() {
+        let order = order_json(
+            long_butterfly(
+                "SPY_011924C400",
+                "SPY_011924C410",
+                "SPY_011924C420",
+                3.0,
+                2.0,
+            )
+            .unwrap(),
+        );
+        assert_eq!(order["complexOrderStrategyType"], "BUTTERFLY");
+        assert_eq!(
+            legs(&order),
+            vec![
+                leg("BUY_TO_OPEN", "SPY_011924C400", 3.0),
+                leg("SELL_TO_OPEN", "SPY_011924C410", 6.0),
+                leg("BUY_TO_OPEN", "SPY_011924C420", 3.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn butterfly_accepts_decimal_strikes() {
+        // 400.2 - 400.1 And 400.3 - 400.2 Differ As f64s
+        assert!(long_butterfly(
+            "SPY_011924C400.1",
+            "SPY_011924C400.2",
+            "SPY_011924C400.3",
+            1.0,
+            0.05,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn rejects_bad_butterflies() {
+        assert_violation(
+            long_butterfly(
+                "SPY_011924C400",
+                "SPY_011924C410",
+                "SPY_011924C425",
+                1.0,
+                2.0,
+            ),
+            "Butterfly Strikes Need Even Spacing, Found 400, 410, And 425",
+        );
+        assert_violation(
+            long_butterfly(
+                "SPY_011924C400",
+                "SPY_011924P410",
+                "SPY_011924C420",
+                1.0,
+                2.0,
+            ),
+            "Legs Need To All Be Calls Or All Be Puts",
+        );
+        assert_violation(
+            long_butterfly(
+                "SPY_011924C400",
+                "SPY_011924C410",
+                "SPY_021624C420",
+                1.0,
+                2.0,
+            ),
+            "Legs Need The Same Expiration",
+        );
+    }
+
+    #[test]
+    fn builds_iron_condor() {
+        let order = order_json(
+            short_iron_condor(
+                "SPY_011924P380",
+                "SPY_011924P390",
+                "SPY_011924C410",
+                "SPY_011924C420",
+                1.0,
+                3.0,
+            )
+            .unwrap(),
+        );
+        assert_eq!(order["complexOrderStrategyType"], "IRON_CONDOR");
+        assert_eq!(order["orderType"], "NET_CREDIT");
+        assert_eq!(
+            legs(&order),
+            vec![
+                leg("BUY_TO_OPEN", "SPY_011924P380", 1.0),
+                leg("SELL_TO_OPEN", "SPY_011924P390", 1.0),
+                leg("SELL_TO_OPEN", "SPY_011924C410", 1.0),
+                leg("BUY_TO_OPEN", "SPY_011924C420", 1.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_bad_iron_condors() {
+        assert_violation(
+            short_iron_condor(
+                "SPY_011924P390",
+                "SPY_011924P380",
+                "SPY_011924C410",
+                "SPY_011924C420",
+                1.0,
+                3.0,
+            ),
+            "Leg Strikes Need To Increase In Order",
+        );
+        assert_violation(
+            short_iron_condor(
+                "SPY_011924C380",
+                "SPY_011924C390",
+                "SPY_011924C410",
+                "SPY_011924C420",
+                1.0,
+                3.0,
+            ),
+            "SPY_011924C380 Needs To Be A Put",
+        );
+    }
+
+    #[test]
+    fn builds_collar() {
+        let order = order_json(
+            collar_with_stock("SPY", "SPY_011924P390", "SPY_011924C410", 2.0, 400.0).unwrap(),
+        );
+        assert_eq!(order["complexOrderStrategyType"], "COLLAR_WITH_STOCK");
+        assert_eq!(
+            order["orderLegCollection"][0]["instrument"]["assetType"],
+            "EQUITY"
+        );
+        assert_eq!(
+            legs(&order),
+            vec![
+                leg("BUY", "SPY", 200.0),
+                leg("BUY_TO_OPEN", "SPY_011924P390", 2.0),
+                leg("SELL_TO_OPEN", "SPY_011924C410", 2.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_bad_collars() {
+        assert_violation(
+            collar_with_stock("QQQ", "SPY_011924P390", "SPY_011924C410", 1.0, 400.0),
+            "Collar Options Are On SPY, Not QQQ",
+        );
+        assert_violation(
+            collar_with_stock("SPY", "SPY_011924P410", "SPY_011924C390", 1.0, 400.0),
+            "Leg Strikes Need To Increase In Order",
+        );
+    }
+
+    #[test]
+    fn builds_vertical_roll() {
+        let order = order_json(
+            vertical_roll(
+                "SPY_011924C400",
+                "SPY_011924C410",
+                "SPY_021624C400",
+                "SPY_021624C410",
+                1.0,
+                OrderType::NetDebit,
+                0.5,
+            )
+            .unwrap(),
+        );
+        assert_eq!(order["complexOrderStrategyType"], "VERTICAL_ROLL");
+        assert_eq!(order["orderType"], "NET_DEBIT");
+        assert_eq!(
+            legs(&order),
+            vec![
+                leg("SELL_TO_CLOSE", "SPY_011924C400", 1.0),
+                leg("BUY_TO_CLOSE", "SPY_011924C410", 1.0),
+                leg("BUY_TO_OPEN", "SPY_021624C400", 1.0),
+                leg("SELL_TO_OPEN", "SPY_021624C410", 1.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_bad_vertical_rolls() {
+        assert_violation(
+            vertical_roll(
+                "SPY_011924C400",
+                "SPY_011924C410",
+                "SPY_021624C400",
+                "SPY_021624C410",
+                1.0,
+                OrderType::Limit,
+                0.5,
+            ),
+            "Vertical Roll Needs A NET_DEBIT Or NET_CREDIT Order Type, Found Limit",
+        );
+        assert_violation(
+            vertical_roll(
+                "SPY_011924C400",
+                "SPY_021624C410",
+                "SPY_021624C400",
+                "SPY_021624C410",
+                1.0,
+                OrderType::NetCredit,
+                0.5,
+            ),
+            "Legs Need The Same Expiration",
+        );
+    }
+}
@@ -23,6 +23,15 @@ pub struct SimpleOrder {
     pub requested_destination: String,
     pub destination_link_name: String,
     pub price: Option<f64>,
+    pub stop_price: Option<f64>,
+    pub stop_price_link_basis: Option<String>,
+    pub stop_price_link_type: Option<String>,
+    pub stop_price_offset: Option<f64>,
+    pub stop_type: Option<String>,
+    pub price_link_basis: Option<String>,
+    pub price_link_type: Option<String>,
+    pub activation_price: Option<f64>,
+    pub special_instruction: Option<String>,
     pub order_leg_collection: Vec<OrderLegCollection>,
     pub order_strategy_type: String,
     pub order_id: i64,
@@ -33,6 +42,8 @@ pub struct SimpleOrder {
     pub close_time: String,
     pub tag: String,
     pub account_id: i64,
+    #[serde(default)]
+    pub child_order_strategies: Vec<ChildOrderStrategy>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -57,6 +68,17 @@ pub struct SimpleSavedOrder {
     pub duration: String,
     pub order_type: String,
     pub complex_order_strategy_type: String,
+    pub quantity: Option<f64>,
+    pub price: Option<f64>,
+    pub stop_price: Option<f64>,
+    pub stop_price_link_basis: Option<String>,
+    pub stop_price_link_type: Option<String>,
+    pub stop_price_offset: Option<f64>,
+    pub stop_type: Option<String>,
+    pub price_link_basis: Option<String>,
+    pub price_link_type: Option<String>,
+    pub activation_price: Option<f64>,
+    pub special_instruction: Option<String>,
     pub order_leg_collection: Vec<OrderLegCollection>,
     pub order_strategy_type: String,
     pub cancelable: bool,
@@ -65,8 +87,9 @@ pub struct SimpleSavedOrder {
     pub saved_time: String,
 }
 
+// Children Of OCO Orders Hold Their Own Children Instead Of Legs, So Every Field Has A Default
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
 pub struct ChildOrderStrategy {
     pub session: String,
     pub duration: String,
@@ -78,7 +101,7 @@ pub struct ChildOrderStrategy {
     pub remaining_quantity: f64,
     pub requested_destination: String,
     pub destination_link_name: String,
-    pub price: f64,
+    pub price: Option<f64>,
     pub order_leg_collection: Vec<OrderLegCollection>,
     pub order_strategy_type: String,
     pub order_id: i64,
@@ -89,10 +112,17 @@ pub struct ChildOrderStrategy {
     pub close_time: String,
     pub tag: String,
     pub account_id: i64,
-    #[serde(default)]
     pub order_activity_collection: Vec<OrderActivityCollection>,
     pub stop_price: Option<f64>,
+    pub stop_price_link_basis: Option<String>,
+    pub stop_price_link_type: Option<String>,
+    pub stop_price_offset: Option<f64>,
     pub stop_type: Option<String>,
+    pub price_link_basis: Option<String>,
+    pub price_link_type: Option<String>,
+    pub activation_price: Option<f64>,
+    pub special_instruction: Option<String>,
+    pub child_order_strategies: Vec<ChildOrderStrategy>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
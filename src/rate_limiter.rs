@@ -0,0 +1,200 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// TD Ameritrade Allows 120 Requests Per Minute
+const DEFAULT_REQUESTS_PER_MINUTE: u32 = 120;
+
+/// Token bucket rate limiter that delays requests instead of letting TD Ameritrade throttle them
+///
+/// Clones share the same buckets, so one limiter can be shared by every clone of a client (or by several clients)
+///
+/// Buckets
+/// - market_data: All requests except order placement. Default is 120 requests per minute
+/// - orders: Placing, replacing, and canceling orders. Default is 120 requests per minute
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    market_data: Arc<Mutex<Bucket>>,
+    orders: Arc<Mutex<Bucket>>,
+}
+
+// Which Bucket A Request Draws From
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RateLimitBucket {
+    MarketData,
+    Orders,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_sec: f64,
+    last_refill: Instant,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        RateLimiter::new(DEFAULT_REQUESTS_PER_MINUTE, DEFAULT_REQUESTS_PER_MINUTE)
+    }
+}
+
+impl RateLimiter {
+    /// Create rate limiter with separate per-minute limits for market data and order requests
+    pub fn new(market_data_per_minute: u32, orders_per_minute: u32) -> RateLimiter {
+        RateLimiter {
+            market_data: Arc::new(Mutex::new(Bucket::new(market_data_per_minute))),
+            orders: Arc::new(Mutex::new(Bucket::new(orders_per_minute))),
+        }
+    }
+
+    // Take A Token And Return How Long To Wait Before Sending
+    pub(crate) fn reserve(&self, bucket: RateLimitBucket) -> Duration {
+        let bucket: &Mutex<Bucket> = match bucket {
+            RateLimitBucket::MarketData => &self.market_data,
+            RateLimitBucket::Orders => &self.orders,
+        };
+
+        bucket.lock().unwrap().reserve()
+    }
+}
+
+impl Bucket {
+    fn new(per_minute: u32) -> Bucket {
+        let capacity: f64 = per_minute.max(1) as f64;
+
+        Bucket {
+            capacity,
+            tokens: capacity,
+            refill_per_sec: capacity / 60.0,
+            last_refill: Instant::now(),
+        }
+    }
+
+    fn reserve(&mut self) -> Duration {
+        self.reserve_at(Instant::now())
+    }
+
+    fn reserve_at(&mut self, now: Instant) -> Duration {
+        // Refill Tokens Since Last Request
+        let elapsed: f64 = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.last_refill = now;
+
+        // Take Token. A Negative Balance Is Paid Back By Waiting
+        self.tokens -= 1.0;
+
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.refill_per_sec)
+        }
+    }
+}
+
+impl RateLimitBucket {
+    // Order Placement Is Throttled Separately From Everything Else
+    pub(crate) fn for_request(is_get: bool, url: &str) -> RateLimitBucket {
+        if !is_get && url.contains("/orders") {
+            RateLimitBucket::Orders
+        } else {
+            RateLimitBucket::MarketData
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_delays_once_empty() {
+        let mut bucket = Bucket::new(60);
+        let start: Instant = bucket.last_refill;
+
+        for _ in 0..60 {
+            assert_eq!(bucket.reserve_at(start), Duration::ZERO);
+        }
+
+        // One Token Per Second, And Each Waiting Request Queues Behind The Last
+        assert_eq!(bucket.reserve_at(start), Duration::from_secs(1));
+        assert_eq!(bucket.reserve_at(start), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn bucket_refills_up_to_capacity() {
+        let mut bucket = Bucket::new(60);
+        let start: Instant = bucket.last_refill;
+
+        for _ in 0..60 {
+            bucket.reserve_at(start);
+        }
+
+        assert_eq!(
+            bucket.reserve_at(start + Duration::from_secs(1)),
+            Duration::ZERO
+        );
+        assert_eq!(
+            bucket.reserve_at(start + Duration::from_secs(1)),
+            Duration::from_secs(1)
+        );
+
+        // Refilling For Longer Than A Minute Doesn't Exceed Capacity
+        let later: Instant = start + Duration::from_secs(600);
+
+        for _ in 0..60 {
+            assert_eq!(bucket.reserve_at(later), Duration::ZERO);
+        }
+
+        assert!(bucket.reserve_at(later) > Duration::ZERO);
+    }
+
+    #[test]
+    fn zero_limit_still_allows_a_request() {
+        let mut bucket = Bucket::new(0);
+        let start: Instant = bucket.last_refill;
+
+        assert_eq!(bucket.reserve_at(start), Duration::ZERO);
+        assert_eq!(bucket.reserve_at(start), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn order_changes_use_orders_bucket() {
+        let orders_url = "https://api.tdameritrade.com/v1/accounts/123/orders";
+
+        assert_eq!(
+            RateLimitBucket::for_request(false, orders_url),
+            RateLimitBucket::Orders
+        );
+        assert_eq!(
+            RateLimitBucket::for_request(false, &format!("{}/456", orders_url)),
+            RateLimitBucket::Orders
+        );
+        assert_eq!(
+            RateLimitBucket::for_request(true, orders_url),
+            RateLimitBucket::MarketData
+        );
+        assert_eq!(
+            RateLimitBucket::for_request(
+                false,
+                "https://api.tdameritrade.com/v1/accounts/123/watchlists"
+            ),
+            RateLimitBucket::MarketData
+        );
+    }
+
+    #[test]
+    fn clones_share_buckets() {
+        let rate_limiter = RateLimiter::new(1, 1);
+        let clone = rate_limiter.clone();
+
+        assert_eq!(
+            rate_limiter.reserve(RateLimitBucket::Orders),
+            Duration::ZERO
+        );
+        assert!(clone.reserve(RateLimitBucket::Orders) > Duration::from_secs(59));
+
+        // Buckets Are Independent
+        assert_eq!(clone.reserve(RateLimitBucket::MarketData), Duration::ZERO);
+        assert!(rate_limiter.reserve(RateLimitBucket::MarketData) > Duration::from_secs(59));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::error::{ParseError, TDAClientError};
+use reqwest::header::{HeaderMap, LOCATION};
+use serde::de::DeserializeOwned;
+
+// Max Number Of Characters Of Body Included In Parse Errors
+const BODY_SNIPPET_LEN: usize = 200;
+
+// Deserialize Response Body, Reporting Endpoint And Start Of Body On Failure
+pub(crate) fn parse_json<T: DeserializeOwned>(
+    endpoint: &str,
+    body: &str,
+) -> Result<T, TDAClientError> {
+    serde_json::from_str::<T>(body).map_err(|err| {
+        TDAClientError::ClientParseError(ParseError {
+            endpoint: Some(endpoint.to_string()),
+            body_snippet: Some(body.chars().take(BODY_SNIPPET_LEN).collect()),
+            source: err,
+        })
+    })
+}
+
+// Get Location Header From Response Headers
+pub(crate) fn location(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(LOCATION)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string())
+}
+
+// Parse ID From The End Of A Location Header
+// Example: https://api.tdameritrade.com/v1/accounts/123/orders/456 -> 456
+// Only Called After A Successful Status, So A Missing Or Unparseable Header Still Means The Request Succeeded
+pub(crate) fn parse_location_id(location: Option<String>) -> Option<i64> {
+    location?
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .and_then(|id| id.parse::<i64>().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::preferences::Preferences;
+    use serde_json::error::Category;
+
+    // Parse Preferences, Returning The Parse Error
+    fn parse_error(body: &str) -> ParseError {
+        match parse_json::<Preferences>("GET accounts/{accountId}/preferences", body) {
+            Err(TDAClientError::ClientParseError(err)) => err,
+            other => panic!("Expected ClientParseError, Got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parses_valid_body() {
+        let preferences: Preferences = parse_json(
+            "GET accounts/{accountId}/preferences",
+            &serde_json::to_string(&Preferences::default()).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(preferences, Preferences::default());
+    }
+
+    #[test]
+    fn parse_errors_carry_endpoint_and_body() {
+        for (body, category) in [
+            ("<html>Gateway Timeout</html>", Category::Syntax),
+            (r#"{"expressTrading":"yes"}"#, Category::Data),
+            (r#"{"expressTrading":true"#, Category::Eof),
+            ("", Category::Eof),
+        ] {
+            let err = parse_error(body);
+
+            assert_eq!(
+                err.endpoint.as_deref(),
+                Some("GET accounts/{accountId}/preferences")
+            );
+            assert_eq!(err.body_snippet.as_deref(), Some(body));
+            assert_eq!(err.source.classify(), category, "{}", body);
+            assert!(err
+                .to_string()
+                .starts_with("GET accounts/{accountId}/preferences: "));
+        }
+    }
+
+    #[test]
+    fn body_snippet_is_truncated() {
+        let body = format!("[{}]", "\"é\",".repeat(BODY_SNIPPET_LEN));
+        let err = parse_error(&body);
+
+        assert_eq!(
+            err.body_snippet.unwrap(),
+            body.chars().take(BODY_SNIPPET_LEN).collect::<String>()
+        );
+    }
+
+    #[test]
+    fn parses_location_id() {
+        assert_eq!(
+            parse_location_id(Some(
+                "https://api.tdameritrade.com/v1/accounts/123/orders/456".into()
+            )),
+            Some(456)
+        );
+        assert_eq!(
+            parse_location_id(Some(
+                "https://api.tdameritrade.com/v1/accounts/123/orders/456/".into()
+            )),
+            Some(456)
+        );
+    }
+
+    #[test]
+    fn missing_location_id_is_none() {
+        assert_eq!(parse_location_id(None), None);
+        assert_eq!(
+            parse_location_id(Some(
+                "https://api.tdameritrade.com/v1/accounts/123/orders".into()
+            )),
+            None
+        );
+    }
+}
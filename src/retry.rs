@@ -0,0 +1,200 @@
+use crate::error::TDAClientError;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Retry policy for transient failures (connection errors, timeouts, 5xx responses, and 429s)
+///
+/// Only GET requests are retried by default, so an order is never submitted twice unless `retry_non_idempotent` is enabled
+///
+/// Parameters
+/// - max_retries: Number of retries after the first attempt. Default is `3`
+/// - initial_backoff: Delay before the first retry. Doubles after each retry. Default is 500 milliseconds
+/// - max_backoff: Upper bound on the delay between retries, including delays from `Retry-After`. Default is 30 seconds
+/// - retry_non_idempotent: Also retry POST, PUT, PATCH, and DELETE requests such as `place_order`. Default is `false`
+///
+/// A `Retry-After` header on a 429 response is used instead of the exponential backoff, capped at `max_backoff`
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            ..RetryPolicy::default()
+        }
+    }
+
+    /// Never retry
+    pub fn none() -> RetryPolicy {
+        RetryPolicy::new(0)
+    }
+
+    // Initial Backoff
+    pub fn set_initial_backoff(mut self, initial_backoff: Duration) -> RetryPolicy {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    // Max Backoff
+    pub fn set_max_backoff(mut self, max_backoff: Duration) -> RetryPolicy {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    // Retry Non Idempotent Requests
+    pub fn set_retry_non_idempotent(mut self, retry_non_idempotent: bool) -> RetryPolicy {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    // Delay Before Next Attempt, Or None If The Request Shouldn't Be Retried
+    pub(crate) fn delay(
+        &self,
+        is_get: bool,
+        attempt: u32,
+        err: &TDAClientError,
+    ) -> Option<Duration> {
+        // Out Of Retries Or Not Allowed To Retry
+        if attempt >= self.max_retries || (!is_get && !self.retry_non_idempotent) {
+            return None;
+        }
+
+        match err {
+            // Honor Retry-After, But Don't Let The Server Block The Caller Past Max Backoff
+            TDAClientError::RateLimited {
+                retry_after: Some(secs),
+                ..
+            } => Some(Duration::from_secs(*secs).min(self.max_backoff)),
+            TDAClientError::RateLimited { .. } | TDAClientError::ServerError { .. } => {
+                Some(self.backoff(attempt))
+            }
+            TDAClientError::ClientConnectionError(err) if err.is_connect() || err.is_timeout() => {
+                Some(self.backoff(attempt))
+            }
+            _ => None,
+        }
+    }
+
+    // Exponential Backoff With Jitter Between Half And All Of The Delay
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponential: Duration = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff);
+
+        exponential.mul_f64(0.5 + 0.5 * jitter())
+    }
+}
+
+// Random Number Between 0 And 1
+fn jitter() -> f64 {
+    let random: u64 = RandomState::new().build_hasher().finish();
+    random as f64 / u64::MAX as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server_error() -> TDAClientError {
+        TDAClientError::ServerError {
+            status: 503,
+            message: String::new(),
+        }
+    }
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::new(3)
+            .set_initial_backoff(Duration::from_millis(100))
+            .set_max_backoff(Duration::from_millis(300))
+    }
+
+    #[test]
+    fn stops_after_max_retries() {
+        assert!(policy().delay(true, 2, &server_error()).is_some());
+        assert!(policy().delay(true, 3, &server_error()).is_none());
+        assert!(RetryPolicy::none()
+            .delay(true, 0, &server_error())
+            .is_none());
+    }
+
+    #[test]
+    fn retries_non_idempotent_only_when_allowed() {
+        assert!(policy().delay(false, 0, &server_error()).is_none());
+        assert!(policy()
+            .set_retry_non_idempotent(true)
+            .delay(false, 0, &server_error())
+            .is_some());
+    }
+
+    #[test]
+    fn honors_retry_after() {
+        let err = TDAClientError::RateLimited {
+            message: String::new(),
+            retry_after: Some(7),
+        };
+
+        assert_eq!(
+            RetryPolicy::default().delay(true, 0, &err),
+            Some(Duration::from_secs(7))
+        );
+    }
+
+    #[test]
+    fn caps_retry_after_at_max_backoff() {
+        let err = TDAClientError::RateLimited {
+            message: String::new(),
+            retry_after: Some(u64::MAX),
+        };
+
+        assert_eq!(
+            policy().delay(true, 0, &err),
+            Some(Duration::from_millis(300))
+        );
+    }
+
+    #[test]
+    fn backs_off_exponentially_with_jitter_up_to_max() {
+        let rate_limited = TDAClientError::RateLimited {
+            message: String::new(),
+            retry_after: None,
+        };
+
+        for (attempt, full_delay) in [(0, 100), (1, 200), (2, 300)] {
+            for err in [&server_error(), &rate_limited] {
+                let delay = policy().delay(true, attempt, err).unwrap();
+                let full_delay = Duration::from_millis(full_delay);
+
+                assert!(
+                    delay >= full_delay / 2 && delay <= full_delay,
+                    "{:?}",
+                    delay
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn does_not_retry_client_errors() {
+        let err = TDAClientError::BadRequest(String::new());
+
+        assert!(policy().delay(true, 0, &err).is_none());
+    }
+}
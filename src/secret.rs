@@ -0,0 +1,90 @@
+use crate::TDAClientError;
+use reqwest::header::HeaderValue;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Debug, Display, Formatter};
+use zeroize::{Zeroize, Zeroizing};
+
+/// String holding a credential, such as an access or refresh token
+///
+/// `Debug` and `Display` print `[REDACTED]` instead of the value, and the value is zeroed in memory when dropped. Use `expose_secret` to read it
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(secret: String) -> Secret {
+        Secret(secret)
+    }
+
+    /// Read the secret value. Avoid logging the returned value
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    // Bearer Authorization Header. Marked Sensitive, And The Formatted Copy Is Zeroed
+    pub(crate) fn bearer_header(&self) -> Result<HeaderValue, TDAClientError> {
+        let bearer: Zeroizing<String> = Zeroizing::new(format!("Bearer {}", self.0));
+
+        let mut header: HeaderValue = HeaderValue::from_str(&bearer).map_err(|_| {
+            TDAClientError::Unauthorized("Access Token Isn't A Valid Header Value".into())
+        })?;
+        header.set_sensitive(true);
+
+        Ok(header)
+    }
+}
+
+impl From<String> for Secret {
+    fn from(secret: String) -> Self {
+        Secret(secret)
+    }
+}
+
+impl Debug for Secret {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Secret([REDACTED])")
+    }
+}
+
+impl Display for Secret {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "[REDACTED]")
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_and_display_are_redacted() {
+        let secret = Secret::new("hunter2".into());
+
+        assert_eq!(format!("{:?}", secret), "Secret([REDACTED])");
+        assert_eq!(format!("{}", secret), "[REDACTED]");
+        assert_eq!(secret.expose_secret(), "hunter2");
+    }
+
+    #[test]
+    fn serializes_as_plain_string() {
+        let secret: Secret = serde_json::from_str(r#""hunter2""#).unwrap();
+
+        assert_eq!(secret.expose_secret(), "hunter2");
+        assert_eq!(serde_json::to_string(&secret).unwrap(), r#""hunter2""#);
+    }
+
+    #[test]
+    fn bearer_header_is_sensitive() {
+        let header: HeaderValue = Secret::new("access".into()).bearer_header().unwrap();
+
+        assert_eq!(header, "Bearer access");
+        assert!(header.is_sensitive());
+        assert!(Secret::new("bad\ntoken".into()).bearer_header().is_err());
+    }
+}
@@ -1,85 +1,158 @@
-use crate::token::{read_token_file, NewAccessToken, Token};
+use crate::config::AuthConfig;
+use crate::response;
+use crate::secret::Secret;
+use crate::token::{NewAccessToken, Token};
+use crate::token_store::{TokenStore, TokenStoreLock};
 use crate::TDAClientError;
 use reqwest::blocking::Client;
 use std::collections::HashMap;
-use std::fs;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-#[derive(Default, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub struct SyncAuth {
+    reqwest_client: Client,
     client_id: String,
     redirect_uri: String,
-    token_path: String,
-    access_token: String,
-    refresh_token: String,
+    token_store: Arc<dyn TokenStore>,
+    auth_config: AuthConfig,
+    access_token: Secret,
+    refresh_token: Secret,
     access_token_expire_time: u64,
     refresh_token_expire_time: u64,
+    refresh_lock: Arc<Mutex<()>>,
 }
 
 impl SyncAuth {
     // Create New Auth
     pub fn new(
+        reqwest_client: Client,
         client_id: String,
         redirect_uri: String,
-        token_path: String,
+        token_store: Arc<dyn TokenStore>,
+        auth_config: AuthConfig,
     ) -> Result<Self, TDAClientError> {
-        // Read Token File
-        let token: Token = read_token_file(&token_path)?;
+        // Load Token
+        let token: Token = token_store.load()?;
 
         // Create New Auth
-        let mut auth: SyncAuth = SyncAuth::default();
-
-        // Fill Parameters
-        auth.client_id = client_id;
-        auth.redirect_uri = redirect_uri;
-        auth.token_path = token_path;
-        auth.access_token = token.access_token;
-        auth.access_token_expire_time = 0;
-        auth.refresh_token = token.refresh_token;
-        auth.refresh_token_expire_time = token.refresh_token_expires_in;
+        let auth: SyncAuth = SyncAuth {
+            reqwest_client,
+            client_id,
+            redirect_uri,
+            token_store,
+            auth_config,
+            access_token_expire_time: token.access_token_expire_time(),
+            refresh_token_expire_time: token.refresh_token_expire_time(),
+            access_token: token.access_token,
+            refresh_token: token.refresh_token,
+            refresh_lock: Arc::new(Mutex::new(())),
+        };
 
         // Return Auth
         Ok(auth)
     }
 
     // Get Access Token
-    pub fn get_access_token(&self) -> String {
-        self.access_token.clone()
+    pub fn get_access_token(&self) -> &Secret {
+        &self.access_token
+    }
+
+    // Check If Tokens Are Valid Without Refreshing Them
+    pub fn is_token_valid(&self) -> bool {
+        // Get Current Time
+        let epoch_time: u64 = self.auth_config.clock.now();
+
+        epoch_time + self.auth_config.refresh_token_margin.as_secs()
+            < self.refresh_token_expire_time
+            && epoch_time + self.auth_config.access_token_margin.as_secs()
+                < self.access_token_expire_time
+    }
+
+    // Time Until check_token_validity Will Refresh A Token
+    pub fn next_refresh_in(&self) -> Duration {
+        // Get Current Time
+        let epoch_time: u64 = self.auth_config.clock.now();
+
+        // Earliest Of The Access And Refresh Token Refresh Times
+        let access_refresh_time: u64 = self
+            .access_token_expire_time
+            .saturating_sub(self.auth_config.access_token_margin.as_secs());
+        let refresh_refresh_time: u64 = self
+            .refresh_token_expire_time
+            .saturating_sub(self.auth_config.refresh_token_margin.as_secs());
+
+        Duration::from_secs(
+            access_refresh_time
+                .min(refresh_refresh_time)
+                .saturating_sub(epoch_time),
+        )
     }
 
     // Check Token Validity
     pub fn check_token_validity(&mut self) -> Result<(), TDAClientError> {
-        // Get Current Time
-        let now: SystemTime = SystemTime::now();
-        let epoch_time: u64 = now
-            .duration_since(UNIX_EPOCH)
-            .expect("Time somehow went backwards")
-            .as_secs();
-
-        // Check If Refresh Token Is Valid
-        if epoch_time > self.refresh_token_expire_time {
-            self.request_new_token("refresh_token")?;
+        self.refresh_tokens_ahead(Duration::ZERO)
+    }
+
+    // Refresh Tokens That Will Need Refreshing Within lead. The Background Refresher Uses A Lead So Requests Never Have To Refresh
+    pub fn refresh_tokens_ahead(&mut self, lead: Duration) -> Result<(), TDAClientError> {
+        // Get Current Time, Moved Ahead By lead
+        let epoch_time: u64 = self.auth_config.clock.now() + lead.as_secs();
+
+        // Expired Refresh Token Can't Be Used To Get A New One
+        if self.auth_config.clock.now() >= self.refresh_token_expire_time {
+            return Err(self.notify_reauth(TDAClientError::RefreshTokenExpired));
+        }
+
+        // Replace Refresh Token Before It Expires
+        if epoch_time + self.auth_config.refresh_token_margin.as_secs()
+            >= self.refresh_token_expire_time
+        {
+            self.request_new_token("refresh_token", lead)
+                .map_err(|err| self.notify_reauth(err))?;
         }
 
-        // Check If Access Token Is Valid
-        if epoch_time > self.access_token_expire_time {
-            self.request_new_token("access_token")?;
+        // Refresh Access Token Before It Expires
+        if epoch_time + self.auth_config.access_token_margin.as_secs()
+            >= self.access_token_expire_time
+        {
+            self.request_new_token("access_token", lead)
+                .map_err(|err| self.notify_reauth(err))?;
         }
 
         Ok(())
     }
 
-    // Request New Token
-    pub fn request_new_token(&mut self, token_type: &str) -> Result<(), TDAClientError> {
-        // Create Client
-        let reqwest_client: Client = Client::new();
+    // Request New Token. Auth Is Only Updated After The New Token Is Saved
+    pub fn request_new_token(
+        &mut self,
+        token_type: &str,
+        lead: Duration,
+    ) -> Result<(), TDAClientError> {
+        // Only One Clone Refreshes At A Time, Even If The Token Store Doesn't Lock
+        let refresh_lock: Arc<Mutex<()>> = self.refresh_lock.clone();
+        let _refresh_guard = refresh_lock.lock().unwrap();
+
+        // Only One Process Refreshes At A Time
+        let _lock: TokenStoreLock = self.token_store.lock()?;
+
+        // Reload Token. Another Clone Or Process May Have Refreshed It While This One Waited For The Lock
+        let stored_token: Token = self.token_store.load()?;
 
-        // Create Hashmap To Store Params
+        if self.is_refreshed_elsewhere(token_type, &stored_token, lead) {
+            self.update_tokens(&stored_token);
+            return Ok(());
+        }
+
+        // Create Hashmap To Store Params. Stored Refresh Token Is The Newest One
         let mut params: HashMap<String, String> = HashMap::new();
 
         // Parameters
         params.insert("grant_type".into(), "refresh_token".into());
-        params.insert("refresh_token".into(), self.refresh_token.clone());
+        params.insert(
+            "refresh_token".into(),
+            stored_token.refresh_token.expose_secret().to_string(),
+        );
         params.insert("client_id".into(), self.client_id.clone());
         params.insert("redirect_uri".into(), self.redirect_uri.clone());
 
@@ -88,77 +161,299 @@ impl SyncAuth {
             params.insert("access_type".into(), "offline".into());
         }
 
-        // Create Url
-        let url: String = "https://api.tdameritrade.com/v1/oauth2/token".into();
-
         // Request New Token
-        let res = reqwest_client.post(url).form(&params).send()?;
+        let res = self
+            .reqwest_client
+            .post(&self.auth_config.auth_url)
+            .form(&params)
+            .send()?;
 
-        // Get Response Text
+        // Get Response
+        let status = res.status();
+        let headers = res.headers().clone();
         let res_text: String = res.text()?;
 
-        // Get JSON Response For Access Token
+        // Refresh Failed
+        if !status.is_success() {
+            return Err(TDAClientError::from_token_response(
+                status, &headers, &res_text,
+            ));
+        }
+
+        // Get Current Time
+        let epoch_time: u64 = self.auth_config.clock.now();
+
+        let token: Token = if token_type == "access_token" {
+            // Get JSON Response For Access Token
+            let res_json: NewAccessToken = response::parse_json("POST oauth2/token", &res_text)?;
+
+            // Replace Access Token
+            let mut token: Token = stored_token;
+            token.replace_access_token_expire_time(epoch_time + res_json.expires_in.max(0) as u64);
+            token.replace_access_token(res_json.access_token);
+
+            token
+        } else {
+            // Get JSON Response For Refresh Token
+            let mut token: Token = response::parse_json("POST oauth2/token", &res_text)?;
+
+            // Set Expire Times
+            token.set_expire_times(epoch_time);
+
+            token
+        };
+
+        // Save Token
+        self.token_store.save(&token)?;
+
+        // Update Auth Values
+        self.update_tokens(&token);
+
+        Ok(())
+    }
+
+    // Whether The Stored Token Was Refreshed By Another Process And Doesn't Need Refreshing
+    fn is_refreshed_elsewhere(
+        &self,
+        token_type: &str,
+        stored_token: &Token,
+        lead: Duration,
+    ) -> bool {
+        // Get Current Time, Moved Ahead By lead
+        let epoch_time: u64 = self.auth_config.clock.now() + lead.as_secs();
+
         if token_type == "access_token" {
-            match serde_json::from_str::<NewAccessToken>(&res_text) {
-                Ok(res_json) => {
-                    // Get Current Time
-                    let now: SystemTime = SystemTime::now();
-                    let epoch_time: u64 = now
-                        .duration_since(UNIX_EPOCH)
-                        .expect("Time Somehow Went Backwards")
-                        .as_secs();
-
-                    // Update Auth Values
-                    self.access_token = res_json.access_token.clone();
-                    self.access_token_expire_time = epoch_time + 1500; // Access Token Expires After 25 Minutes
-
-                    // Read Token File
-                    let mut token: Token = read_token_file(&self.token_path)?;
-
-                    // Replace Access Token
-                    token.replace_access_token(res_json.access_token);
-
-                    // Write To File
-                    fs::write(self.token_path.clone(), token.to_string())?
-                }
-                _ => (),
-            }
+            stored_token.access_token != self.access_token
+                && epoch_time + self.auth_config.access_token_margin.as_secs()
+                    < stored_token.access_token_expire_time()
+        } else {
+            stored_token.refresh_token != self.refresh_token
+                && epoch_time + self.auth_config.refresh_token_margin.as_secs()
+                    < stored_token.refresh_token_expire_time()
         }
+    }
 
-        // Get JSON Response For Refresh Token
-        if token_type == "refresh_token" {
-            match serde_json::from_str::<Token>(&res_text) {
-                Ok(res_json) => {
-                    // Get Current Time
-                    let now: SystemTime = SystemTime::now();
-                    let epoch_time: u64 = now
-                        .duration_since(UNIX_EPOCH)
-                        .expect("Time Somehow Went Backwards")
-                        .as_secs();
-
-                    // Update Auth Values
-                    self.access_token = res_json.access_token.clone();
-                    self.access_token_expire_time = epoch_time + 1500; // Access Token Expires After 25 Minutes
-                    self.refresh_token = res_json.refresh_token.clone();
-                    self.refresh_token_expire_time = epoch_time + 6480000; // Refresh Token Expires After 75 Days
-
-                    // Read Token File
-                    let mut token: Token = read_token_file(&self.token_path)?;
-
-                    // Replace Tokens
-                    token.replace_access_token(res_json.access_token);
-                    token.replace_refresh_token(res_json.refresh_token);
-
-                    // Replace Refresh Token Expire Time
-                    token.replace_refresh_token_expire_time(epoch_time + 6480000); // Refresh Token Expires After 75 Days
-
-                    // Write To File
-                    fs::write(self.token_path.clone(), token.to_string())?
-                }
-                _ => (),
+    // Check If Both Auths Hold The Same Tokens
+    pub fn holds_same_tokens(&self, other: &Self) -> bool {
+        self.access_token == other.access_token && self.refresh_token == other.refresh_token
+    }
+
+    // Use Tokens And Expire Times From Token
+    fn update_tokens(&mut self, token: &Token) {
+        self.access_token = token.access_token.clone();
+        self.access_token_expire_time = token.access_token_expire_time();
+        self.refresh_token = token.refresh_token.clone();
+        self.refresh_token_expire_time = token.refresh_token_expire_time();
+    }
+
+    // Run Reauth Callback If The Error Means A New Token Must Be Created
+    fn notify_reauth(&self, err: TDAClientError) -> TDAClientError {
+        if err.requires_reauth() {
+            if let Some(reauth_callback) = &self.auth_config.reauth_callback {
+                reauth_callback.call(&err);
             }
         }
 
-        Ok(())
+        err
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{Clock, ManualClock};
+    use crate::config::{ReauthCallback, DEFAULT_REFRESH_TOKEN_MARGIN};
+    use crate::test_util::temp_path;
+    use crate::test_util::{auth_config, oauth_server, token, NOW, REFRESH_EXPIRE_TIME};
+    use crate::token_store::{FileTokenStore, MemoryTokenStore};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn auth(
+        auth_url: &str,
+        token_store: Arc<dyn TokenStore>,
+        clock: &ManualClock,
+        reauth_callback: Option<ReauthCallback>,
+    ) -> SyncAuth {
+        SyncAuth::new(
+            Client::new(),
+            "client_id@AMER.OAUTHAP".into(),
+            "https://localhost".into(),
+            token_store,
+            auth_config(auth_url, clock, reauth_callback),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn valid_token_is_not_refreshed() {
+        // Nothing Listens On The Auth URL, So Any Refresh Would Fail
+        let clock = ManualClock::new(NOW);
+        let token_store = Arc::new(MemoryTokenStore::new(token("access", "refresh")));
+        let mut auth = auth("http://127.0.0.1:1/", token_store, &clock, None);
+
+        clock.advance(Duration::from_secs(1800 - 301));
+
+        assert!(auth.is_token_valid());
+        assert_eq!(auth.next_refresh_in(), Duration::from_secs(1));
+        auth.check_token_validity().unwrap();
+        assert_eq!(auth.get_access_token().expose_secret(), "access");
+    }
+
+    #[test]
+    fn access_token_is_refreshed_within_margin() {
+        let (auth_url, requests) = oauth_server(
+            r#"{"access_token": "new_access", "scope": "", "expires_in": 1800, "token_type": "Bearer"}"#,
+        );
+        let clock = ManualClock::new(NOW);
+        let token_store = Arc::new(MemoryTokenStore::new(token("access", "refresh")));
+        let mut auth = auth(&auth_url, token_store.clone(), &clock, None);
+
+        // Inside The 300 Second Margin
+        clock.advance(Duration::from_secs(1800 - 300));
+        assert!(!auth.is_token_valid());
+
+        auth.check_token_validity().unwrap();
+
+        let request: String = requests.try_recv().unwrap();
+        assert!(request.contains("grant_type=refresh_token"));
+        assert!(request.contains("refresh_token=refresh"));
+        assert!(!request.contains("access_type=offline"));
+
+        // New Access Token Is Used And Saved With Its Expire Time
+        assert_eq!(auth.get_access_token().expose_secret(), "new_access");
+        assert!(auth.is_token_valid());
+
+        let stored_token: Token = token_store.load().unwrap();
+        assert_eq!(stored_token.access_token.expose_secret(), "new_access");
+        assert_eq!(stored_token.access_token_expire_time(), clock.now() + 1800);
+        assert_eq!(
+            stored_token.refresh_token_expire_time(),
+            REFRESH_EXPIRE_TIME
+        );
+    }
+
+    #[test]
+    fn refresh_token_is_replaced_within_margin() {
+        let (auth_url, requests) = oauth_server(
+            r#"{"access_token": "new_access", "refresh_token": "new_refresh", "scope": "", "expires_in": 1800, "refresh_token_expires_in": 7776000, "token_type": "Bearer"}"#,
+        );
+        let clock = ManualClock::new(NOW);
+        let token_store = Arc::new(MemoryTokenStore::new(token("access", "refresh")));
+        let mut auth = auth(&auth_url, token_store.clone(), &clock, None);
+
+        // Inside The 15 Day Refresh Token Margin
+        clock.set(REFRESH_EXPIRE_TIME - DEFAULT_REFRESH_TOKEN_MARGIN.as_secs());
+
+        auth.check_token_validity().unwrap();
+
+        assert!(requests.try_recv().unwrap().contains("access_type=offline"));
+        assert!(requests.try_recv().is_err());
+
+        let stored_token: Token = token_store.load().unwrap();
+        assert_eq!(stored_token.refresh_token.expose_secret(), "new_refresh");
+        assert_eq!(
+            stored_token.refresh_token_expire_time(),
+            clock.now() + 7776000
+        );
+        assert!(auth.is_token_valid());
+    }
+
+    #[test]
+    fn refresh_ahead_refreshes_before_requests_would() {
+        let (auth_url, requests) = oauth_server(
+            r#"{"access_token": "new_access", "scope": "", "expires_in": 1800, "token_type": "Bearer"}"#,
+        );
+        let clock = ManualClock::new(NOW);
+        let token_store = Arc::new(MemoryTokenStore::new(token("access", "refresh")));
+        let mut auth = auth(&auth_url, token_store, &clock, None);
+
+        // Requests Wouldn't Refresh Yet, But A 60 Second Lead Does
+        clock.advance(Duration::from_secs(1800 - 300 - 30));
+        assert!(auth.is_token_valid());
+
+        auth.refresh_tokens_ahead(Duration::from_secs(60)).unwrap();
+
+        assert!(requests.try_recv().is_ok());
+        assert_eq!(auth.get_access_token().expose_secret(), "new_access");
+    }
+
+    #[test]
+    fn expired_refresh_token_requires_reauth() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let reauth_calls = calls.clone();
+        let reauth_callback = ReauthCallback::new(move |err| {
+            assert!(matches!(err, TDAClientError::RefreshTokenExpired));
+            reauth_calls.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let clock = ManualClock::new(NOW);
+        let token_store = Arc::new(MemoryTokenStore::new(token("access", "refresh")));
+        let mut auth = auth(
+            "http://127.0.0.1:1/",
+            token_store,
+            &clock,
+            Some(reauth_callback),
+        );
+
+        clock.set(REFRESH_EXPIRE_TIME);
+
+        let err: TDAClientError = auth.check_token_validity().unwrap_err();
+        assert!(matches!(err, TDAClientError::RefreshTokenExpired));
+        assert!(err.requires_reauth());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn concurrent_refreshes_hit_the_token_endpoint_once() {
+        let (auth_url, requests) = oauth_server(
+            r#"{"access_token": "new_access", "scope": "", "expires_in": 1800, "token_type": "Bearer"}"#,
+        );
+        let clock = ManualClock::new(NOW + 1800 - 300);
+
+        // Memory Token Store Doesn't Lock, So Only The Auth's Refresh Lock Keeps Clones From Refreshing Together
+        let token_store = Arc::new(MemoryTokenStore::new(token("access", "refresh")));
+        let auth = auth(&auth_url, token_store, &clock, None);
+
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let mut auth: SyncAuth = auth.clone();
+                std::thread::spawn(move || {
+                    auth.check_token_validity().unwrap();
+                    auth.get_access_token().expose_secret().to_string()
+                })
+            })
+            .collect();
+
+        for thread in threads {
+            assert_eq!(thread.join().unwrap(), "new_access");
+        }
+
+        assert!(requests.try_recv().is_ok());
+        assert!(requests.try_recv().is_err());
+    }
+
+    #[test]
+    fn adopts_token_refreshed_by_another_process() {
+        let token_path: String = temp_path("shared_token.json");
+        let token_store = Arc::new(FileTokenStore::new(token_path.clone()));
+        token_store.save(&token("access", "refresh")).unwrap();
+
+        // Nothing Listens On The Auth URL, So Any Refresh Would Fail
+        let clock = ManualClock::new(NOW);
+        let mut auth = auth("http://127.0.0.1:1/", token_store.clone(), &clock, None);
+
+        // Another Process Refreshes The Token File
+        clock.advance(Duration::from_secs(1800 - 300));
+        let mut refreshed_token: Token = token("other_access", "refresh");
+        refreshed_token.replace_access_token_expire_time(clock.now() + 1800);
+        token_store.save(&refreshed_token).unwrap();
+
+        auth.check_token_validity().unwrap();
+
+        assert_eq!(auth.get_access_token().expose_secret(), "other_access");
+        assert!(auth.is_token_valid());
+
+        std::fs::remove_file(format!("{}.lock", token_path)).unwrap();
+        std::fs::remove_file(token_path).unwrap();
     }
 }
@@ -1,45 +1,78 @@
 use crate::builders::{OptionChain, PriceHistory};
+use crate::client_builder::TDAClientBuilder;
+use crate::config::ClientConfig;
 use crate::error::TDAClientError;
-use crate::synchronous::{auth_sync::SyncAuth, requests_sync};
+use crate::market_data::MarketDataRequest;
+use crate::response;
+use crate::secret::Secret;
+use crate::synchronous::{auth_sync::SyncAuth, refresher_sync::SyncTokenRefresher, requests_sync};
+use crate::token_store::TokenStore;
 use itertools::Itertools;
 use reqwest::blocking::Client;
 use std::collections::HashMap;
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
 
+/// Clones share the same auth state, so a token refreshed by one clone is used by all of them
+#[derive(Clone)]
 pub struct SyncTDAClient {
     reqwest_client: Client,
-    auth: RwLock<SyncAuth>,
+    config: ClientConfig,
+    auth: Arc<RwLock<SyncAuth>>,
 }
 
 impl SyncTDAClient {
     /// Create New Synchronous TDAClient
+    ///
+    /// Use `TDAClientBuilder` to configure URLs, timeouts, user agent, proxy, or the reqwest client
     pub fn new(
         client_id: String,
         redirect_uri: String,
         token_path: String,
     ) -> Result<SyncTDAClient, TDAClientError> {
-        // Create Auth Client
-        let auth: SyncAuth = SyncAuth::new(client_id, redirect_uri, token_path)?;
+        TDAClientBuilder::new(client_id, redirect_uri, token_path).build_sync()
+    }
 
-        // Create Reqwest Client
-        let reqwest_client: Client = Client::new();
+    /// Create New Synchronous TDAClient That Loads And Saves Its Token With A `TokenStore`
+    pub fn with_token_store(
+        client_id: String,
+        redirect_uri: String,
+        token_store: impl TokenStore + 'static,
+    ) -> Result<SyncTDAClient, TDAClientError> {
+        TDAClientBuilder::with_token_store(client_id, redirect_uri, token_store).build_sync()
+    }
 
-        // Create New SyncTDAClient
-        Ok(SyncTDAClient {
+    // Create SyncTDAClient From Configured Parts
+    pub(crate) fn from_parts(reqwest_client: Client, auth: SyncAuth, config: ClientConfig) -> Self {
+        SyncTDAClient {
             reqwest_client,
-            auth: RwLock::new(auth),
-        })
+            config,
+            auth: Arc::new(RwLock::new(auth)),
+        }
     }
 
-    pub fn clone(&self) -> Self {
-        // Create a new `SyncTDAClient` instance with the same values as the current instance.
-        let clone = Self {
-            reqwest_client: self.reqwest_client.clone(),
-            auth: RwLock::new(self.auth.read().unwrap().clone()),
-        };
+    // Get Access Token, Refreshing It First If It Expired
+    fn get_access_token(&self) -> Result<Secret, TDAClientError> {
+        // Token Is Still Valid
+        {
+            let auth = self.auth.read().unwrap();
+
+            if auth.is_token_valid() {
+                return Ok(auth.get_access_token().clone());
+            }
+        }
+
+        // Only One Clone Refreshes At A Time. The Others Wait, Then Reuse The New Token
+        let mut auth = self.auth.write().unwrap();
+        auth.check_token_validity()?;
 
-        // Return the cloned `SyncTDAClient` instance.
-        clone
+        Ok(auth.get_access_token().clone())
+    }
+
+    /// Start refreshing the token in the background shortly before it expires, so requests don't wait on token refreshes
+    ///
+    /// The refresher stops when the returned handle is shut down or dropped
+    pub fn start_token_refresher(&self) -> SyncTokenRefresher {
+        SyncTokenRefresher::start(self.auth.clone())
     }
 
     //// Accounts ////
@@ -56,13 +89,8 @@ impl SyncTDAClient {
         acct_id: i64,
         fields: Option<&Vec<&str>>,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Create HashMap To Store Parameters
         let mut params: HashMap<String, String> = HashMap::new();
@@ -79,7 +107,13 @@ impl SyncTDAClient {
         let url: String = format!("accounts/{}", acct_id);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
     }
 
     /// Account balances, positions, and orders for all linked accounts
@@ -89,13 +123,8 @@ impl SyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/account-access/apis/get/accounts-0
     pub fn get_accounts(&self, fields: Option<&Vec<&str>>) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Create HashMap To Store Parameters
         let mut params: HashMap<String, String> = HashMap::new();
@@ -112,7 +141,13 @@ impl SyncTDAClient {
         let url: String = "accounts".into();
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
     }
 
     //// Instruments ////
@@ -134,26 +169,20 @@ impl SyncTDAClient {
         symbol: &str,
         projection: &str,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
-        // Create HashMap To Store Parameters
-        let mut params: HashMap<String, String> = HashMap::new();
-
-        // Parameters
-        params.insert("symbol".into(), symbol.into());
-        params.insert("projection".into(), projection.into());
-
-        // Format URL
-        let url: String = "instruments".into();
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::search_instruments(symbol, projection);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            request.params,
+            request.url,
+        )
     }
 
     /// Get an instrument by CUSIP
@@ -163,22 +192,20 @@ impl SyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/instruments/apis/get/instruments/%7Bcusip%7D
     pub fn get_instrument(&self, cusip: &str) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
-
-        // Create HashMap To Store Parameters
-        let params: HashMap<String, String> = HashMap::new();
+        let access_token: Secret = self.get_access_token()?;
 
-        // Format URL
-        let url: String = format!("instruments/{}", cusip);
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::get_instrument(cusip);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            request.params,
+            request.url,
+        )
     }
 
     //// Market Hours ////
@@ -195,29 +222,21 @@ impl SyncTDAClient {
         markets: &Vec<&str>,
         date: &str,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
-        // Create HashMap To Store Parameters
-        let mut params: HashMap<String, String> = HashMap::new();
-
-        // Convert Vector To String
-        let cleaned_markets: String = markets.join(",");
-
-        // Parameters
-        params.insert("markets".into(), cleaned_markets);
-        params.insert("date".into(), date.into());
-
-        // Format URL
-        let url: String = "marketdata/hours".into();
+        // Create Request
+        let request: MarketDataRequest =
+            MarketDataRequest::get_hours_for_multiple_markets(markets, date);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            request.params,
+            request.url,
+        )
     }
 
     /// Retrieve market hours for specified single market
@@ -232,25 +251,21 @@ impl SyncTDAClient {
         market: &str,
         date: &str,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
-
-        // Create HashMap To Store Parameters
-        let mut params: HashMap<String, String> = HashMap::new();
+        let access_token: Secret = self.get_access_token()?;
 
-        // Parameter
-        params.insert("date".into(), date.into());
-
-        // Format URL
-        let url: String = format!("marketdata/{}/hours", market);
+        // Create Request
+        let request: MarketDataRequest =
+            MarketDataRequest::get_hours_for_single_market(market, date);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            request.params,
+            request.url,
+        )
     }
 
     //// Movers ////
@@ -269,26 +284,20 @@ impl SyncTDAClient {
         direction: &str,
         change: &str,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
-
-        // Create HashMap To Store Parameters
-        let mut params: HashMap<String, String> = HashMap::new();
+        let access_token: Secret = self.get_access_token()?;
 
-        // Parameters
-        params.insert("direction".into(), direction.into());
-        params.insert("change".into(), change.into());
-
-        // Format URL
-        let url: String = format!("marketdata/{}/movers", index);
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::get_movers(index, direction, change);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            request.params,
+            request.url,
+        )
     }
 
     //// Option Chains ////
@@ -316,80 +325,20 @@ impl SyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/option-chains/apis/get/marketdata/chains
     pub fn get_option_chain(&self, option_params: &OptionChain) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
-
-        // Create HashMap To Store Parameters
-        let mut params: HashMap<String, String> = HashMap::new();
-
-        // Optional Parameters
-        if option_params.strike_count != -1 {
-            params.insert("strikeCount".into(), option_params.strike_count.to_string());
-        }
-
-        if option_params.interval != -1.0 {
-            params.insert("interval".into(), option_params.interval.to_string());
-        }
-
-        if option_params.strike != -1.0 {
-            params.insert("strike".into(), option_params.strike.to_string());
-        }
-
-        if option_params.from_date != "" {
-            params.insert("fromDate".into(), option_params.from_date.clone());
-        }
-
-        if option_params.to_date != "" {
-            params.insert("toDate".into(), option_params.to_date.clone());
-        }
-
-        if option_params.volatility != -1.0 {
-            params.insert("volatility".into(), option_params.volatility.to_string());
-        }
-
-        if option_params.underlying_price != -1.0 {
-            params.insert(
-                "underlyingPrice".into(),
-                option_params.underlying_price.to_string(),
-            );
-        }
-
-        if option_params.interest_rate != -1.0 {
-            params.insert(
-                "interestRate".into(),
-                option_params.interest_rate.to_string(),
-            );
-        }
-
-        if option_params.days_to_expiration != -1 {
-            params.insert(
-                "daysToExpiration".into(),
-                option_params.days_to_expiration.to_string(),
-            );
-        }
+        let access_token: Secret = self.get_access_token()?;
 
-        // Required Parameters
-        params.insert("symbol".into(), option_params.symbol.clone());
-        params.insert("contractType".into(), option_params.contract_type.clone());
-        params.insert(
-            "includeQuotes".into(),
-            option_params.include_quotes.to_string(),
-        );
-        params.insert("strategy".into(), option_params.strategy.clone());
-        params.insert("range".into(), option_params.range.clone());
-        params.insert("expMonth".into(), option_params.expiration_month.clone());
-        params.insert("optionType".into(), option_params.option_type.clone());
-
-        // Format URL
-        let url: String = "marketdata/chains".into();
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::get_option_chain(option_params);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            request.params,
+            request.url,
+        )
     }
 
     //// Preferences ////
@@ -401,13 +350,8 @@ impl SyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/user-principal/apis/get/accounts/%7BaccountId%7D/preferences-0
     pub fn get_preferences(&self, acct_id: i64) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Create HashMap To Store Parameters
         let params: HashMap<String, String> = HashMap::new();
@@ -416,7 +360,13 @@ impl SyncTDAClient {
         let url: String = format!("accounts/{}/preferences", acct_id);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
     }
 
     /// Update preferences for a specific account. Please note that the directOptionsRouting and directEquityRouting values cannot be modified via this operation
@@ -431,13 +381,8 @@ impl SyncTDAClient {
         acct_id: i64,
         preference_spec: &str,
     ) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Convert Preference Spec To String
         let body: String = preference_spec.into();
@@ -446,7 +391,9 @@ impl SyncTDAClient {
         let url: String = format!("accounts/{}/preferences", acct_id);
 
         // Update Preferences
-        requests_sync::put(&self.reqwest_client, access_token, body, url)
+        requests_sync::put(&self.reqwest_client, &self.config, access_token, body, url)?;
+
+        Ok(())
     }
 
     //// Price History ////
@@ -469,47 +416,20 @@ impl SyncTDAClient {
         &self,
         history_params: &PriceHistory,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
-        // Create HashMap To Store Parameters
-        let mut params: HashMap<String, String> = HashMap::new();
-
-        // Optional Parameters
-        if history_params.period != -1 {
-            params.insert("period".into(), history_params.period.to_string());
-        }
-
-        if history_params.start_date != -1 {
-            params.insert("startDate".into(), history_params.start_date.to_string());
-        }
-
-        if history_params.end_date != -1 {
-            params.insert("endDate".into(), history_params.end_date.to_string());
-        }
-
-        // Required Parameters
-        params.insert("periodType".into(), history_params.period_type.clone());
-        params.insert(
-            "frequencyType".into(),
-            history_params.frequency_type.clone(),
-        );
-        params.insert("frequency".into(), history_params.frequency.to_string());
-        params.insert(
-            "needExtendedHoursData".into(),
-            history_params.need_extended_hours_data.to_string(),
-        );
-
-        // Format URL
-        let url: String = format!("marketdata/{}/pricehistory", history_params.symbol);
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::get_price_history(history_params);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            request.params,
+            request.url,
+        )
     }
 
     //// Quotes ////
@@ -521,22 +441,20 @@ impl SyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/quotes/apis/get/marketdata/%7Bsymbol%7D/quotes
     pub fn get_quote(&self, symbol: &str) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
-        // Create HashMap To Store Parameters
-        let params: HashMap<String, String> = HashMap::new();
-
-        // Format URL
-        let url: String = format!("marketdata/{}/quotes", symbol);
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::get_quote(symbol);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            request.params,
+            request.url,
+        )
     }
 
     /// Get quote for one or more symbols
@@ -546,28 +464,20 @@ impl SyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/quotes/apis/get/marketdata/quotes
     pub fn get_quotes(&self, symbols: &Vec<&str>) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
-
-        // Create HashMap To Store Parameters
-        let mut params: HashMap<String, String> = HashMap::new();
+        let access_token: Secret = self.get_access_token()?;
 
-        // Convert Vector To String
-        let cleaned_symbols: String = symbols.join(",");
-
-        // Parameter
-        params.insert("symbol".into(), cleaned_symbols);
-
-        // Format URL
-        let url: String = "marketdata/quotes".into();
+        // Create Request
+        let request: MarketDataRequest = MarketDataRequest::get_quotes(symbols);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            request.params,
+            request.url,
+        )
     }
 
     //// Trading ////
@@ -580,13 +490,8 @@ impl SyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/account-access/apis/get/accounts/%7BaccountId%7D/orders/%7BorderId%7D-0
     pub fn get_order(&self, acct_id: i64, order_id: i64) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Create HashMap To Store Parameters
         let params: HashMap<String, String> = HashMap::new();
@@ -595,7 +500,13 @@ impl SyncTDAClient {
         let url: String = format!("accounts/{}/orders/{}", acct_id, order_id);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
     }
 
     /// Orders for a specific account
@@ -616,13 +527,8 @@ impl SyncTDAClient {
         to_entered_time: &str,
         status: &str,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Create HashMap To Store Parameters
         let mut params: HashMap<String, String> = HashMap::new();
@@ -637,7 +543,13 @@ impl SyncTDAClient {
         let url: String = format!("accounts/{}/orders", acct_id);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
     }
 
     /// All orders for a specific account or, if acct_id isn't specified, orders will be returned for all linked accounts
@@ -658,13 +570,8 @@ impl SyncTDAClient {
         to_entered_time: &str,
         status: &str,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Create HashMap To Store Parameters
         let mut params: HashMap<String, String> = HashMap::new();
@@ -684,11 +591,19 @@ impl SyncTDAClient {
         let url: String = "orders".into();
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
     }
 
     /// Place an order for a specific account. Order throttle limits may apply
     ///
+    /// Returns the new order's ID, or `None` if the API accepted it without a Location header holding the ID
+    ///
     /// Parameters
     /// - acct_id: Account number
     /// - order_spec: Order body
@@ -696,14 +611,13 @@ impl SyncTDAClient {
     /// Order Examples: https://developer.tdameritrade.com/content/place-order-samples
     ///
     /// Official Documentation: https://developer.tdameritrade.com/account-access/apis/post/accounts/%7BaccountId%7D/orders-0
-    pub fn place_order(&self, acct_id: i64, order_spec: &str) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
+    pub fn place_order(
+        &self,
+        acct_id: i64,
+        order_spec: &str,
+    ) -> Result<Option<i64>, TDAClientError> {
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Convert Order Spec To String
         let body: String = order_spec.into();
@@ -712,11 +626,17 @@ impl SyncTDAClient {
         let url: String = format!("accounts/{}/orders", acct_id);
 
         // Place Order
-        requests_sync::post(&self.reqwest_client, access_token, body, url)
+        let location: Option<String> =
+            requests_sync::post(&self.reqwest_client, &self.config, access_token, body, url)?;
+
+        // Return New Order ID
+        Ok(response::parse_location_id(location))
     }
 
     /// Replace an existing order for an account. The existing order will be replaced by the new order. Once replaced, the old order will be canceled and a new order will be created. Order throttle limits may apply
     ///
+    /// Returns the new order's ID, or `None` if the API accepted it without a Location header holding the ID
+    ///
     /// Parameters
     /// - acct_id: Account number
     /// - order_id: Order number
@@ -730,14 +650,9 @@ impl SyncTDAClient {
         acct_id: i64,
         order_id: i64,
         order_spec: &str,
-    ) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
+    ) -> Result<Option<i64>, TDAClientError> {
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Convert Order Spec To String
         let body: String = order_spec.into();
@@ -746,7 +661,11 @@ impl SyncTDAClient {
         let url: String = format!("accounts/{}/orders/{}", acct_id, order_id);
 
         // Replace Order
-        requests_sync::put(&self.reqwest_client, access_token, body, url)
+        let location: Option<String> =
+            requests_sync::put(&self.reqwest_client, &self.config, access_token, body, url)?;
+
+        // Return New Order ID
+        Ok(response::parse_location_id(location))
     }
 
     /// Cancel a specific order for a specific account. Order throttle limits may apply
@@ -757,19 +676,14 @@ impl SyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/account-access/apis/delete/accounts/%7BaccountId%7D/orders/%7BorderId%7D-0
     pub fn cancel_order(&self, acct_id: i64, order_id: i64) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Format URL
         let url: String = format!("accounts/{}/orders/{}", acct_id, order_id);
 
         // Cancel Order
-        requests_sync::delete(&self.reqwest_client, access_token, url)
+        requests_sync::delete(&self.reqwest_client, &self.config, access_token, url)
     }
 
     /// Specific saved order by its ID, for a specific account
@@ -780,13 +694,8 @@ impl SyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/account-access/apis/get/accounts/%7BaccountId%7D/savedorders/%7BsavedOrderId%7D-0
     pub fn get_saved_order(&self, acct_id: i64, order_id: i64) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Create HashMap To Store Parameters
         let params: HashMap<String, String> = HashMap::new();
@@ -795,7 +704,13 @@ impl SyncTDAClient {
         let url: String = format!("accounts/{}/savedorders/{}", acct_id, order_id);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
     }
 
     /// Saved orders for a specific account
@@ -805,13 +720,8 @@ impl SyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/account-access/apis/get/accounts/%7BaccountId%7D/savedorders-0
     pub fn get_saved_orders_by_path(&self, acct_id: i64) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Create HashMap To Store Parameters
         let params: HashMap<String, String> = HashMap::new();
@@ -820,11 +730,19 @@ impl SyncTDAClient {
         let url: String = format!("accounts/{}/savedorders", acct_id);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
     }
 
     /// Save an order for a specific account
     ///
+    /// Returns the new saved order's ID, or `None` if the API accepted it without a Location header holding the ID
+    ///
     /// Parameters
     /// - acct_id: Account number
     /// - order_spec: Order body
@@ -832,14 +750,13 @@ impl SyncTDAClient {
     /// Order Examples: https://developer.tdameritrade.com/content/place-order-samples
     ///
     /// Official Documentation: https://developer.tdameritrade.com/account-access/apis/post/accounts/%7BaccountId%7D/savedorders-0
-    pub fn create_saved_order(&self, acct_id: i64, order_spec: &str) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
+    pub fn create_saved_order(
+        &self,
+        acct_id: i64,
+        order_spec: &str,
+    ) -> Result<Option<i64>, TDAClientError> {
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Convert Order Spec To String
         let body: String = order_spec.into();
@@ -848,7 +765,11 @@ impl SyncTDAClient {
         let url: String = format!("accounts/{}/savedorders", acct_id);
 
         // Create Saved Order
-        requests_sync::post(&self.reqwest_client, access_token, body, url)
+        let location: Option<String> =
+            requests_sync::post(&self.reqwest_client, &self.config, access_token, body, url)?;
+
+        // Return New Saved Order ID
+        Ok(response::parse_location_id(location))
     }
 
     /// Replace an existing saved order for an account. The existing saved order will be replaced by the new order
@@ -867,13 +788,8 @@ impl SyncTDAClient {
         order_id: i64,
         order_spec: &str,
     ) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Convert Order Spec To String
         let body: String = order_spec.into();
@@ -882,7 +798,9 @@ impl SyncTDAClient {
         let url: String = format!("accounts/{}/savedorders/{}", acct_id, order_id);
 
         // Replace Saved Order
-        requests_sync::put(&self.reqwest_client, access_token, body, url)
+        requests_sync::put(&self.reqwest_client, &self.config, access_token, body, url)?;
+
+        Ok(())
     }
 
     /// Delete a specific saved order for a specific account
@@ -893,19 +811,14 @@ impl SyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/account-access/apis/delete/accounts/%7BaccountId%7D/savedorders/%7BsavedOrderId%7D-0
     pub fn delete_saved_order(&self, acct_id: i64, order_id: i64) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Format URL
         let url: String = format!("accounts/{}/savedorders/{}", acct_id, order_id);
 
         // Delete Saved Order
-        requests_sync::delete(&self.reqwest_client, access_token, url)
+        requests_sync::delete(&self.reqwest_client, &self.config, access_token, url)
     }
 
     //// Transaction History ////
@@ -922,13 +835,8 @@ impl SyncTDAClient {
         acct_id: i64,
         transaction_id: i64,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Create HashMap To Store Parameters
         let params: HashMap<String, String> = HashMap::new();
@@ -937,7 +845,13 @@ impl SyncTDAClient {
         let url: String = format!("accounts/{}/transactions/{}", acct_id, transaction_id);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
     }
 
     /// Transactions for a specific account
@@ -958,13 +872,8 @@ impl SyncTDAClient {
         start_date: &str,
         end_date: &str,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Create HashMap To Store Parameters
         let mut params: HashMap<String, String> = HashMap::new();
@@ -983,7 +892,13 @@ impl SyncTDAClient {
         let url: String = format!("accounts/{}/transactions", acct_id);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
     }
 
     //// User Info ////
@@ -996,15 +911,10 @@ impl SyncTDAClient {
     /// Official Documentation: https://developer.tdameritrade.com/user-principal/apis/get/userprincipals/streamersubscriptionkeys-0
     pub fn get_streamer_subscription_keys(
         &self,
-        acct_ids: &Vec<i64>,
+        acct_ids: &[i64],
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Create HashMap To Store Parameters
         let mut params: HashMap<String, String> = HashMap::new();
@@ -1019,7 +929,13 @@ impl SyncTDAClient {
         let url: String = "userprincipals/streamersubscriptionkeys".into();
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
     }
 
     /// User principal details
@@ -1032,13 +948,8 @@ impl SyncTDAClient {
         &self,
         fields: Option<&Vec<&str>>,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Create HashMap To Store Parameters
         let mut params: HashMap<String, String> = HashMap::new();
@@ -1055,7 +966,13 @@ impl SyncTDAClient {
         let url: String = "userprincipals".into();
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
     }
 
     //// Watchlist ////
@@ -1068,13 +985,8 @@ impl SyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/watchlist/apis/get/accounts/%7BaccountId%7D/watchlists/%7BwatchlistId%7D-0
     pub fn get_watchlist(&self, acct_id: i64, watchlist_id: i64) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Create HashMap To Store Parameters
         let params: HashMap<String, String> = HashMap::new();
@@ -1083,7 +995,13 @@ impl SyncTDAClient {
         let url: String = format!("accounts/{}/watchlists/{}", acct_id, watchlist_id);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
     }
 
     /// All watchlists of an account
@@ -1096,13 +1014,8 @@ impl SyncTDAClient {
         &self,
         acct_id: i64,
     ) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Create HashMap To Store Parameters
         let params: HashMap<String, String> = HashMap::new();
@@ -1111,20 +1024,21 @@ impl SyncTDAClient {
         let url: String = format!("accounts/{}/watchlists", acct_id);
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
     }
 
     /// All watchlists for all of the user's linked accounts
     ///
     /// Official Documentation: https://developer.tdameritrade.com/watchlist/apis/get/accounts/watchlists-0
     pub fn get_watchlists_for_multiple_accounts(&self) -> Result<String, TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Create HashMap To Store Parameters
         let params: HashMap<String, String> = HashMap::new();
@@ -1133,28 +1047,31 @@ impl SyncTDAClient {
         let url: String = "accounts/watchlists".into();
 
         // Return String Response
-        requests_sync::get(&self.reqwest_client, access_token, params, url)
+        requests_sync::get(
+            &self.reqwest_client,
+            &self.config,
+            access_token,
+            params,
+            url,
+        )
     }
 
     /// Create watchlist for specific account. This method does not verify that the symbol or asset type are valid
     ///
+    /// Returns the new watchlist's ID, or `None` if the API accepted it without a Location header holding the ID
+    ///
     /// Parameters
     /// - acct_id: Account number
-    /// - watchlist_spec: Watchlist body
+    /// - watchlist_spec: Watchlist body. Build with `WatchlistBuilder`
     ///
     /// Official Documentation: https://developer.tdameritrade.com/watchlist/apis/post/accounts/%7BaccountId%7D/watchlists-0
     pub fn create_watchlist(
         &self,
         acct_id: i64,
         watchlist_spec: &str,
-    ) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
+    ) -> Result<Option<i64>, TDAClientError> {
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Convert Watchlist Spec To String
         let body: String = watchlist_spec.into();
@@ -1163,7 +1080,11 @@ impl SyncTDAClient {
         let url: String = format!("accounts/{}/watchlists", acct_id);
 
         // Create Watchlist
-        requests_sync::post(&self.reqwest_client, access_token, body, url)
+        let location: Option<String> =
+            requests_sync::post(&self.reqwest_client, &self.config, access_token, body, url)?;
+
+        // Return New Watchlist ID
+        Ok(response::parse_location_id(location))
     }
 
     /// Replace watchlist for a specific account. This method does not verify that the symbol or asset type are valid
@@ -1171,7 +1092,7 @@ impl SyncTDAClient {
     /// Parameters
     /// - acct_id: Account number
     /// - watchlist_id: Watchlist number
-    /// - watchlist_spec: Watchlist body
+    /// - watchlist_spec: Watchlist body. Build with `WatchlistBuilder`
     ///
     /// Official Documentation: https://developer.tdameritrade.com/watchlist/apis/put/accounts/%7BaccountId%7D/watchlists/%7BwatchlistId%7D-0
     pub fn replace_watchlist(
@@ -1180,13 +1101,8 @@ impl SyncTDAClient {
         watchlist_id: i64,
         watchlist_spec: &str,
     ) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Convert Watchlist Spec To String
         let body: String = watchlist_spec.into();
@@ -1195,7 +1111,9 @@ impl SyncTDAClient {
         let url: String = format!("accounts/{}/watchlists/{}", acct_id, watchlist_id);
 
         // Replace Watchlist
-        requests_sync::put(&self.reqwest_client, access_token, body, url)
+        requests_sync::put(&self.reqwest_client, &self.config, access_token, body, url)?;
+
+        Ok(())
     }
 
     /// Partially update watchlist for a specific account: change watchlist name, add to the beginning/end of a watchlist, update or delete items in a watchlist. This method does not verify that the symbol or asset type are valid
@@ -1203,7 +1121,7 @@ impl SyncTDAClient {
     /// Parameters
     /// - acct_id: Account number
     /// - watchlist_id: Watchlist number
-    /// - watchlist_spec: Watchlist body
+    /// - watchlist_spec: Watchlist body. Build with `WatchlistPatchBuilder`
     ///
     /// Official Documentation: https://developer.tdameritrade.com/watchlist/apis/patch/accounts/%7BaccountId%7D/watchlists/%7BwatchlistId%7D-0
     pub fn update_watchlist(
@@ -1212,13 +1130,8 @@ impl SyncTDAClient {
         watchlist_id: i64,
         watchlist_spec: &str,
     ) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Convert Watchlist Spec To String
         let body: String = watchlist_spec.into();
@@ -1227,7 +1140,7 @@ impl SyncTDAClient {
         let url: String = format!("accounts/{}/watchlists/{}", acct_id, watchlist_id);
 
         // Update Watchlist
-        requests_sync::patch(&self.reqwest_client, access_token, body, url)
+        requests_sync::patch(&self.reqwest_client, &self.config, access_token, body, url)
     }
 
     /// Delete watchlist for a specific account
@@ -1238,18 +1151,19 @@ impl SyncTDAClient {
     ///
     /// Official Documentation: https://developer.tdameritrade.com/watchlist/apis/delete/accounts/%7BaccountId%7D/watchlists/%7BwatchlistId%7D-0
     pub fn delete_watchlist(&self, acct_id: i64, watchlist_id: i64) -> Result<(), TDAClientError> {
-        // Check Token Validity
-        {
-            self.auth.write().unwrap().check_token_validity()?;
-        }
-
         // Get Access Token
-        let access_token: String = self.auth.read().unwrap().get_access_token();
+        let access_token: Secret = self.get_access_token()?;
 
         // Format URL
         let url: String = format!("accounts/{}/watchlists/{}", acct_id, watchlist_id);
 
         // Delete Watchlist
-        requests_sync::delete(&self.reqwest_client, access_token, url)
+        requests_sync::delete(&self.reqwest_client, &self.config, access_token, url)
     }
 }
+
+// SyncTDAClient Must Be Shareable Across Threads
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SyncTDAClient>();
+};
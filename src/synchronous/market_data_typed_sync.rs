@@ -0,0 +1,104 @@
+use crate::builders::{OptionChain as OptionChainParams, PriceHistory as PriceHistoryParams};
+use crate::error::TDAClientError;
+use crate::output::{
+    instruments::{InstrumentData, Instruments},
+    market_hours::MarketHours,
+    movers::Mover,
+    option_chains::OptionChain,
+    price_history::PriceHistory,
+    quotes::Quotes,
+};
+use crate::response;
+use crate::SyncMarketDataClient;
+
+// Typed Counterparts Of The String Endpoints, Deserialized Into The Output Structs
+impl SyncMarketDataClient {
+    //// Instruments ////
+
+    /// Same as [`search_instruments`](SyncMarketDataClient::search_instruments), deserialized into `Instruments`
+    pub fn search_instruments_typed(
+        &self,
+        symbol: &str,
+        projection: &str,
+    ) -> Result<Instruments, TDAClientError> {
+        let res: String = self.search_instruments(symbol, projection)?;
+        response::parse_json("GET instruments", &res)
+    }
+
+    /// Same as [`get_instrument`](SyncMarketDataClient::get_instrument), deserialized into `Vec<InstrumentData>`
+    pub fn get_instrument_typed(&self, cusip: &str) -> Result<Vec<InstrumentData>, TDAClientError> {
+        let res: String = self.get_instrument(cusip)?;
+        response::parse_json("GET instruments/{cusip}", &res)
+    }
+
+    //// Market Hours ////
+
+    /// Same as [`get_hours_for_multiple_markets`](SyncMarketDataClient::get_hours_for_multiple_markets), deserialized into `MarketHours`
+    pub fn get_hours_for_multiple_markets_typed(
+        &self,
+        markets: &Vec<&str>,
+        date: &str,
+    ) -> Result<MarketHours, TDAClientError> {
+        let res: String = self.get_hours_for_multiple_markets(markets, date)?;
+        response::parse_json("GET marketdata/hours", &res)
+    }
+
+    /// Same as [`get_hours_for_single_market`](SyncMarketDataClient::get_hours_for_single_market), deserialized into `MarketHours`
+    pub fn get_hours_for_single_market_typed(
+        &self,
+        market: &str,
+        date: &str,
+    ) -> Result<MarketHours, TDAClientError> {
+        let res: String = self.get_hours_for_single_market(market, date)?;
+        response::parse_json("GET marketdata/{market}/hours", &res)
+    }
+
+    //// Movers ////
+
+    /// Same as [`get_movers`](SyncMarketDataClient::get_movers), deserialized into `Vec<Mover>`
+    pub fn get_movers_typed(
+        &self,
+        index: &str,
+        direction: &str,
+        change: &str,
+    ) -> Result<Vec<Mover>, TDAClientError> {
+        let res: String = self.get_movers(index, direction, change)?;
+        response::parse_json("GET marketdata/{index}/movers", &res)
+    }
+
+    //// Option Chains ////
+
+    /// Same as [`get_option_chain`](SyncMarketDataClient::get_option_chain), deserialized into `OptionChain`
+    pub fn get_option_chain_typed(
+        &self,
+        option_params: &OptionChainParams,
+    ) -> Result<OptionChain, TDAClientError> {
+        let res: String = self.get_option_chain(option_params)?;
+        response::parse_json("GET marketdata/chains", &res)
+    }
+
+    //// Price History ////
+
+    /// Same as [`get_price_history`](SyncMarketDataClient::get_price_history), deserialized into `PriceHistory`
+    pub fn get_price_history_typed(
+        &self,
+        history_params: &PriceHistoryParams,
+    ) -> Result<PriceHistory, TDAClientError> {
+        let res: String = self.get_price_history(history_params)?;
+        response::parse_json("GET marketdata/{symbol}/pricehistory", &res)
+    }
+
+    //// Quotes ////
+
+    /// Same as [`get_quote`](SyncMarketDataClient::get_quote), deserialized into `Quotes`
+    pub fn get_quote_typed(&self, symbol: &str) -> Result<Quotes, TDAClientError> {
+        let res: String = self.get_quote(symbol)?;
+        response::parse_json("GET marketdata/{symbol}/quotes", &res)
+    }
+
+    /// Same as [`get_quotes`](SyncMarketDataClient::get_quotes), deserialized into `Quotes`
+    pub fn get_quotes_typed(&self, symbols: &Vec<&str>) -> Result<Quotes, TDAClientError> {
+        let res: String = self.get_quotes(symbols)?;
+        response::parse_json("GET marketdata/quotes", &res)
+    }
+}
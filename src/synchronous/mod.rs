@@ -1,3 +1,7 @@
-mod auth_sync;
+pub(crate) mod auth_sync;
 pub mod client_sync;
+pub(crate) mod market_data_sync;
+mod market_data_typed_sync;
+pub(crate) mod refresher_sync;
 mod requests_sync;
+mod typed_sync;
@@ -0,0 +1,155 @@
+use crate::synchronous::auth_sync::SyncAuth;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, RwLock};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+// Wait Before Retrying A Failed Refresh
+const RETRY_DELAY: Duration = Duration::from_secs(30);
+
+// How Long Before Requests Would Refresh A Token The Refresher Refreshes It
+const REFRESH_LEAD_TIME: Duration = Duration::from_secs(60);
+
+// Shortest Wait Between Refreshes
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Handle to a background thread that refreshes the access token before it expires and replaces the refresh token before it expires. It refreshes a minute before requests would, and a token installed by a request in the meantime is kept
+///
+/// Created by `SyncTDAClient::start_token_refresher`. The thread stops when `shutdown` is called or the handle is dropped, or when the token can't be refreshed without creating a new one
+#[derive(Debug)]
+pub struct SyncTokenRefresher {
+    shutdown_sender: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl SyncTokenRefresher {
+    // Start Refresher Thread
+    pub(crate) fn start(auth: Arc<RwLock<SyncAuth>>) -> SyncTokenRefresher {
+        let (shutdown_sender, shutdown_receiver) = mpsc::channel::<()>();
+
+        let thread: JoinHandle<()> = thread::spawn(move || {
+            let mut wait: Duration = auth
+                .read()
+                .unwrap()
+                .next_refresh_in()
+                .saturating_sub(REFRESH_LEAD_TIME);
+
+            loop {
+                // Sleep Until The Next Refresh Or Shutdown
+                match shutdown_receiver.recv_timeout(wait.max(MIN_REFRESH_INTERVAL)) {
+                    Err(RecvTimeoutError::Timeout) => {}
+                    _ => return,
+                }
+
+                // Refresh A Copy So Requests Keep Using The Current Token Until The New One Is Ready. A Request That Must Refresh Meanwhile Waits On The Auth's Refresh Lock, Then Reuses The New Token
+                let current_auth: SyncAuth = auth.read().unwrap().clone();
+                let mut refreshed_auth: SyncAuth = current_auth.clone();
+
+                wait = match refreshed_auth.refresh_tokens_ahead(REFRESH_LEAD_TIME) {
+                    Ok(()) => {
+                        let mut auth = auth.write().unwrap();
+
+                        // A Request Refreshed The Shared Auth Meanwhile. Keep Its Token
+                        if auth.holds_same_tokens(&current_auth) {
+                            *auth = refreshed_auth;
+                        }
+
+                        auth.next_refresh_in().saturating_sub(REFRESH_LEAD_TIME)
+                    }
+                    // New Token Must Be Created. Reauth Callback Was Already Called
+                    Err(err) if err.requires_reauth() => return,
+                    Err(_) => RETRY_DELAY,
+                };
+            }
+        });
+
+        SyncTokenRefresher {
+            shutdown_sender: Some(shutdown_sender),
+            thread: Some(thread),
+        }
+    }
+
+    /// Stop the refresher and wait for its thread to finish
+    pub fn shutdown(mut self) {
+        self.shutdown_sender.take();
+
+        if let Some(thread) = self.thread.take() {
+            thread.join().ok();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use crate::test_util::{auth_config, oauth_server, token, NOW};
+    use crate::token_store::MemoryTokenStore;
+    use reqwest::blocking::Client;
+    use std::sync::mpsc::Receiver;
+    use std::time::Instant;
+
+    fn shared_auth(
+        token_response: &'static str,
+        clock: &ManualClock,
+    ) -> (Arc<RwLock<SyncAuth>>, Receiver<String>) {
+        let (auth_url, requests) = oauth_server(token_response);
+        let auth: SyncAuth = SyncAuth::new(
+            Client::new(),
+            "client_id@AMER.OAUTHAP".into(),
+            "https://localhost".into(),
+            Arc::new(MemoryTokenStore::new(token("access", "refresh"))),
+            auth_config(&auth_url, clock, None),
+        )
+        .unwrap();
+
+        (Arc::new(RwLock::new(auth)), requests)
+    }
+
+    #[test]
+    fn refreshes_before_requests_would() {
+        // Requests Would Refresh In 30 Seconds, Inside The Refresher's Lead
+        let clock = ManualClock::new(NOW + 1800 - 300 - 30);
+        let (auth, requests) = shared_auth(
+            r#"{"access_token": "new_access", "scope": "", "expires_in": 1800, "token_type": "Bearer"}"#,
+            &clock,
+        );
+        assert!(auth.read().unwrap().is_token_valid());
+
+        let refresher: SyncTokenRefresher = SyncTokenRefresher::start(auth.clone());
+
+        assert!(requests.recv_timeout(Duration::from_secs(10)).is_ok());
+
+        // Wait For The New Token To Be Installed
+        let deadline: Instant = Instant::now() + Duration::from_secs(10);
+        while auth.read().unwrap().get_access_token().expose_secret() != "new_access" {
+            assert!(Instant::now() < deadline, "Token Wasn't Installed");
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        // Next Refresh Is Far Away, So Shutdown Must Interrupt The Wait
+        let shutdown_start: Instant = Instant::now();
+        refresher.shutdown();
+
+        assert!(shutdown_start.elapsed() < Duration::from_secs(1));
+        assert!(requests.try_recv().is_err());
+    }
+
+    #[test]
+    fn stops_on_shutdown_without_refreshing() {
+        let clock = ManualClock::new(NOW);
+        let (auth, requests) = shared_auth("{}", &clock);
+
+        let refresher: SyncTokenRefresher = SyncTokenRefresher::start(auth.clone());
+
+        let shutdown_start: Instant = Instant::now();
+        refresher.shutdown();
+
+        assert!(shutdown_start.elapsed() < Duration::from_secs(1));
+        assert!(requests.try_recv().is_err());
+        assert_eq!(
+            auth.read().unwrap().get_access_token().expose_secret(),
+            "access"
+        );
+    }
+}
@@ -1,42 +1,139 @@
+use crate::config::ClientConfig;
 use crate::error::TDAClientError;
-use reqwest::blocking::Client;
+use crate::response;
+use crate::secret::Secret;
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::header::{HeaderValue, AUTHORIZATION};
 use std::collections::HashMap;
+use std::thread;
 
-static BASE_URL: &str = "https://api.tdameritrade.com/v1/";
+// Return Response If Successful, Otherwise Convert To Error
+fn check_status(res: Response) -> Result<Response, TDAClientError> {
+    // Successful Response
+    if res.status().is_success() {
+        return Ok(res);
+    }
+
+    // Get Status, Headers, And Error Body
+    let status = res.status();
+    let headers = res.headers().clone();
+    let res_text: String = res.text()?;
+
+    Err(TDAClientError::from_status(status, &headers, &res_text))
+}
+
+// Wait Until Rate Limiter Allows Request
+fn wait_for_rate_limit(config: &ClientConfig, is_get: bool, url: &str) {
+    let delay = config.rate_limit_delay(is_get, url);
+
+    if !delay.is_zero() {
+        thread::sleep(delay);
+    }
+}
+
+// Send Request, Retrying Transient Failures According To The Retry Policy
+fn send(
+    config: &ClientConfig,
+    is_get: bool,
+    url: &str,
+    request: impl Fn() -> RequestBuilder,
+) -> Result<Response, TDAClientError> {
+    let mut attempt: u32 = 0;
+
+    loop {
+        // Wait For Rate Limiter
+        wait_for_rate_limit(config, is_get, url);
+
+        // Send Request And Check Status
+        let err: TDAClientError = match request().send() {
+            Ok(res) => match check_status(res) {
+                Ok(res) => return Ok(res),
+                Err(err) => err,
+            },
+            Err(err) => err.into(),
+        };
+
+        // Retry Or Return Error
+        match config.retry_delay(is_get, attempt, &err) {
+            Some(delay) => thread::sleep(delay),
+            None => return Err(err),
+        }
+
+        attempt += 1;
+    }
+}
 
 pub fn delete(
     reqwest_client: &Client,
-    access_token: String,
+    config: &ClientConfig,
+    access_token: Secret,
     url: String,
 ) -> Result<(), TDAClientError> {
     // Format Url
-    let formatted_url: String = format!("{}{}", BASE_URL, url);
+    let formatted_url: String = config.url(&url);
 
-    // Put Request
-    reqwest_client
-        .delete(formatted_url)
-        .header("Authorization", format!("Bearer {}", access_token))
-        .send()?;
+    // Authorization Header
+    let authorization: HeaderValue = access_token.bearer_header()?;
+
+    // Delete Request
+    send(config, false, &url, || {
+        reqwest_client
+            .delete(&formatted_url)
+            .header(AUTHORIZATION, authorization.clone())
+    })?;
 
     Ok(())
 }
 
 pub fn get(
     reqwest_client: &Client,
-    access_token: String,
+    config: &ClientConfig,
+    access_token: Secret,
     params: HashMap<String, String>,
     url: String,
 ) -> Result<String, TDAClientError> {
     // Format Url
-    let formatted_url: String = format!("{}{}", BASE_URL, url);
+    let formatted_url: String = config.url(&url);
+
+    // Authorization Header
+    let authorization: HeaderValue = access_token.bearer_header()?;
 
     // Get Request
-    let res_text = reqwest_client
-        .get(formatted_url)
-        .query(&params)
-        .header("Authorization", format!("Bearer {}", access_token))
-        .send()?
-        .text()?;
+    let res = send(config, true, &url, || {
+        reqwest_client
+            .get(&formatted_url)
+            .query(&params)
+            .header(AUTHORIZATION, authorization.clone())
+    })?;
+
+    // Get Response Text
+    let res_text: String = res.text()?;
+
+    // Return Response Text
+    Ok(res_text)
+}
+
+// Unauthenticated Get Request For Delayed Market Data. The Client Id Is Sent As The apikey Parameter
+pub fn get_with_api_key(
+    reqwest_client: &Client,
+    config: &ClientConfig,
+    api_key: &str,
+    mut params: HashMap<String, String>,
+    url: String,
+) -> Result<String, TDAClientError> {
+    // Format Url
+    let formatted_url: String = config.url(&url);
+
+    // Add API Key
+    params.insert("apikey".into(), api_key.into());
+
+    // Get Request
+    let res = send(config, true, &url, || {
+        reqwest_client.get(&formatted_url).query(&params)
+    })?;
+
+    // Get Response Text
+    let res_text: String = res.text()?;
 
     // Return Response Text
     Ok(res_text)
@@ -44,60 +141,77 @@ pub fn get(
 
 pub fn patch(
     reqwest_client: &Client,
-    access_token: String,
+    config: &ClientConfig,
+    access_token: Secret,
     body: String,
     url: String,
 ) -> Result<(), TDAClientError> {
     // Format Url
-    let formatted_url: String = format!("{}{}", BASE_URL, url);
+    let formatted_url: String = config.url(&url);
 
-    // Put Request
-    reqwest_client
-        .patch(formatted_url)
-        .header("Authorization", format!("Bearer {}", access_token))
-        .header("content-type", "application/json")
-        .body(body)
-        .send()?;
+    // Authorization Header
+    let authorization: HeaderValue = access_token.bearer_header()?;
+
+    // Patch Request
+    send(config, false, &url, || {
+        reqwest_client
+            .patch(&formatted_url)
+            .header(AUTHORIZATION, authorization.clone())
+            .header("content-type", "application/json")
+            .body(body.clone())
+    })?;
 
     Ok(())
 }
 
 pub fn post(
     reqwest_client: &Client,
-    access_token: String,
+    config: &ClientConfig,
+    access_token: Secret,
     body: String,
     url: String,
-) -> Result<(), TDAClientError> {
+) -> Result<Option<String>, TDAClientError> {
     // Format Url
-    let formatted_url: String = format!("{}{}", BASE_URL, url);
+    let formatted_url: String = config.url(&url);
 
-    // Post Request
-    reqwest_client
-        .post(formatted_url)
-        .header("Authorization", format!("Bearer {}", access_token))
-        .header("content-type", "application/json")
-        .body(body)
-        .send()?;
+    // Authorization Header
+    let authorization: HeaderValue = access_token.bearer_header()?;
 
-    Ok(())
+    // Post Request
+    let res = send(config, false, &url, || {
+        reqwest_client
+            .post(&formatted_url)
+            .header(AUTHORIZATION, authorization.clone())
+            .header("content-type", "application/json")
+            .body(body.clone())
+    })?;
+
+    // Return Location Header
+    Ok(response::location(res.headers()))
 }
 
 pub fn put(
     reqwest_client: &Client,
-    access_token: String,
+    config: &ClientConfig,
+    access_token: Secret,
     body: String,
     url: String,
-) -> Result<(), TDAClientError> {
+) -> Result<Option<String>, TDAClientError> {
     // Format Url
-    let formatted_url: String = format!("{}{}", BASE_URL, url);
+    let formatted_url: String = config.url(&url);
 
-    // Put Request
-    reqwest_client
-        .put(formatted_url)
-        .header("Authorization", format!("Bearer {}", access_token))
-        .header("content-type", "application/json")
-        .body(body)
-        .send()?;
+    // Authorization Header
+    let authorization: HeaderValue = access_token.bearer_header()?;
 
-    Ok(())
+    // Put Request
+    let res = send(config, false, &url, || {
+        reqwest_client
+            .put(&formatted_url)
+            .header(AUTHORIZATION, authorization.clone())
+            .header("content-type", "application/json")
+            .body(body.clone())
+    })?;
+
+    // Return Location Header
+    Ok(response::location(res.headers()))
 }
@@ -0,0 +1,282 @@
+use crate::builders::{OptionChain as OptionChainParams, PriceHistory as PriceHistoryParams};
+use crate::error::TDAClientError;
+use crate::output::{
+    accounts::Account,
+    instruments::{InstrumentData, Instruments},
+    market_hours::MarketHours,
+    movers::Mover,
+    option_chains::OptionChain,
+    preferences::Preferences,
+    price_history::PriceHistory,
+    quotes::Quotes,
+    trading::Order,
+    transaction_history::Transaction,
+    user_info::{StreamerSubscriptionKeys, UserPrincipals},
+    watchlist::Watchlist,
+};
+use crate::response;
+use crate::SyncTDAClient;
+
+// Typed Counterparts Of The String Endpoints, Deserialized Into The Output Structs
+impl SyncTDAClient {
+    //// Accounts ////
+
+    /// Same as [`get_account`](SyncTDAClient::get_account), deserialized into `Account`
+    pub fn get_account_typed(
+        &self,
+        acct_id: i64,
+        fields: Option<&Vec<&str>>,
+    ) -> Result<Account, TDAClientError> {
+        let res: String = self.get_account(acct_id, fields)?;
+        response::parse_json("GET accounts/{accountId}", &res)
+    }
+
+    /// Same as [`get_accounts`](SyncTDAClient::get_accounts), deserialized into `Vec<Account>`
+    pub fn get_accounts_typed(
+        &self,
+        fields: Option<&Vec<&str>>,
+    ) -> Result<Vec<Account>, TDAClientError> {
+        let res: String = self.get_accounts(fields)?;
+        response::parse_json("GET accounts", &res)
+    }
+
+    //// Instruments ////
+
+    /// Same as [`search_instruments`](SyncTDAClient::search_instruments), deserialized into `Instruments`
+    pub fn search_instruments_typed(
+        &self,
+        symbol: &str,
+        projection: &str,
+    ) -> Result<Instruments, TDAClientError> {
+        let res: String = self.search_instruments(symbol, projection)?;
+        response::parse_json("GET instruments", &res)
+    }
+
+    /// Same as [`get_instrument`](SyncTDAClient::get_instrument), deserialized into `Vec<InstrumentData>`
+    pub fn get_instrument_typed(&self, cusip: &str) -> Result<Vec<InstrumentData>, TDAClientError> {
+        let res: String = self.get_instrument(cusip)?;
+        response::parse_json("GET instruments/{cusip}", &res)
+    }
+
+    //// Market Hours ////
+
+    /// Same as [`get_hours_for_multiple_markets`](SyncTDAClient::get_hours_for_multiple_markets), deserialized into `MarketHours`
+    pub fn get_hours_for_multiple_markets_typed(
+        &self,
+        markets: &Vec<&str>,
+        date: &str,
+    ) -> Result<MarketHours, TDAClientError> {
+        let res: String = self.get_hours_for_multiple_markets(markets, date)?;
+        response::parse_json("GET marketdata/hours", &res)
+    }
+
+    /// Same as [`get_hours_for_single_market`](SyncTDAClient::get_hours_for_single_market), deserialized into `MarketHours`
+    pub fn get_hours_for_single_market_typed(
+        &self,
+        market: &str,
+        date: &str,
+    ) -> Result<MarketHours, TDAClientError> {
+        let res: String = self.get_hours_for_single_market(market, date)?;
+        response::parse_json("GET marketdata/{market}/hours", &res)
+    }
+
+    //// Movers ////
+
+    /// Same as [`get_movers`](SyncTDAClient::get_movers), deserialized into `Vec<Mover>`
+    pub fn get_movers_typed(
+        &self,
+        index: &str,
+        direction: &str,
+        change: &str,
+    ) -> Result<Vec<Mover>, TDAClientError> {
+        let res: String = self.get_movers(index, direction, change)?;
+        response::parse_json("GET marketdata/{index}/movers", &res)
+    }
+
+    //// Option Chains ////
+
+    /// Same as [`get_option_chain`](SyncTDAClient::get_option_chain), deserialized into `OptionChain`
+    pub fn get_option_chain_typed(
+        &self,
+        option_params: &OptionChainParams,
+    ) -> Result<OptionChain, TDAClientError> {
+        let res: String = self.get_option_chain(option_params)?;
+        response::parse_json("GET marketdata/chains", &res)
+    }
+
+    //// Preferences ////
+
+    /// Same as [`get_preferences`](SyncTDAClient::get_preferences), deserialized into `Preferences`
+    pub fn get_preferences_typed(&self, acct_id: i64) -> Result<Preferences, TDAClientError> {
+        let res: String = self.get_preferences(acct_id)?;
+        response::parse_json("GET accounts/{accountId}/preferences", &res)
+    }
+
+    //// Price History ////
+
+    /// Same as [`get_price_history`](SyncTDAClient::get_price_history), deserialized into `PriceHistory`
+    pub fn get_price_history_typed(
+        &self,
+        history_params: &PriceHistoryParams,
+    ) -> Result<PriceHistory, TDAClientError> {
+        let res: String = self.get_price_history(history_params)?;
+        response::parse_json("GET marketdata/{symbol}/pricehistory", &res)
+    }
+
+    //// Quotes ////
+
+    /// Same as [`get_quote`](SyncTDAClient::get_quote), deserialized into `Quotes`
+    pub fn get_quote_typed(&self, symbol: &str) -> Result<Quotes, TDAClientError> {
+        let res: String = self.get_quote(symbol)?;
+        response::parse_json("GET marketdata/{symbol}/quotes", &res)
+    }
+
+    /// Same as [`get_quotes`](SyncTDAClient::get_quotes), deserialized into `Quotes`
+    pub fn get_quotes_typed(&self, symbols: &Vec<&str>) -> Result<Quotes, TDAClientError> {
+        let res: String = self.get_quotes(symbols)?;
+        response::parse_json("GET marketdata/quotes", &res)
+    }
+
+    //// Trading ////
+
+    /// Same as [`get_order`](SyncTDAClient::get_order), deserialized into `Order`
+    pub fn get_order_typed(&self, acct_id: i64, order_id: i64) -> Result<Order, TDAClientError> {
+        let res: String = self.get_order(acct_id, order_id)?;
+        response::parse_json("GET accounts/{accountId}/orders/{orderId}", &res)
+    }
+
+    /// Same as [`get_orders_by_path`](SyncTDAClient::get_orders_by_path), deserialized into `Vec<Order>`
+    pub fn get_orders_by_path_typed(
+        &self,
+        acct_id: i64,
+        max_results: i64,
+        from_entered_time: &str,
+        to_entered_time: &str,
+        status: &str,
+    ) -> Result<Vec<Order>, TDAClientError> {
+        let res: String = self.get_orders_by_path(
+            acct_id,
+            max_results,
+            from_entered_time,
+            to_entered_time,
+            status,
+        )?;
+        response::parse_json("GET accounts/{accountId}/orders", &res)
+    }
+
+    /// Same as [`get_orders_by_query`](SyncTDAClient::get_orders_by_query), deserialized into `Vec<Order>`
+    pub fn get_orders_by_query_typed(
+        &self,
+        acct_id: Option<i64>,
+        max_results: i64,
+        from_entered_time: &str,
+        to_entered_time: &str,
+        status: &str,
+    ) -> Result<Vec<Order>, TDAClientError> {
+        let res: String = self.get_orders_by_query(
+            acct_id,
+            max_results,
+            from_entered_time,
+            to_entered_time,
+            status,
+        )?;
+        response::parse_json("GET orders", &res)
+    }
+
+    /// Same as [`get_saved_order`](SyncTDAClient::get_saved_order), deserialized into `Order`
+    pub fn get_saved_order_typed(
+        &self,
+        acct_id: i64,
+        order_id: i64,
+    ) -> Result<Order, TDAClientError> {
+        let res: String = self.get_saved_order(acct_id, order_id)?;
+        response::parse_json("GET accounts/{accountId}/savedorders/{savedOrderId}", &res)
+    }
+
+    /// Same as [`get_saved_orders_by_path`](SyncTDAClient::get_saved_orders_by_path), deserialized into `Vec<Order>`
+    pub fn get_saved_orders_by_path_typed(
+        &self,
+        acct_id: i64,
+    ) -> Result<Vec<Order>, TDAClientError> {
+        let res: String = self.get_saved_orders_by_path(acct_id)?;
+        response::parse_json("GET accounts/{accountId}/savedorders", &res)
+    }
+
+    //// Transaction History ////
+
+    /// Same as [`get_transaction`](SyncTDAClient::get_transaction), deserialized into `Transaction`
+    pub fn get_transaction_typed(
+        &self,
+        acct_id: i64,
+        transaction_id: i64,
+    ) -> Result<Transaction, TDAClientError> {
+        let res: String = self.get_transaction(acct_id, transaction_id)?;
+        response::parse_json(
+            "GET accounts/{accountId}/transactions/{transactionId}",
+            &res,
+        )
+    }
+
+    /// Same as [`get_transactions`](SyncTDAClient::get_transactions), deserialized into `Vec<Transaction>`
+    pub fn get_transactions_typed(
+        &self,
+        acct_id: i64,
+        transaction_type: &str,
+        symbol: Option<&str>,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<Transaction>, TDAClientError> {
+        let res: String =
+            self.get_transactions(acct_id, transaction_type, symbol, start_date, end_date)?;
+        response::parse_json("GET accounts/{accountId}/transactions", &res)
+    }
+
+    //// User Info ////
+
+    /// Same as [`get_streamer_subscription_keys`](SyncTDAClient::get_streamer_subscription_keys), deserialized into `StreamerSubscriptionKeys`
+    pub fn get_streamer_subscription_keys_typed(
+        &self,
+        acct_ids: &[i64],
+    ) -> Result<StreamerSubscriptionKeys, TDAClientError> {
+        let res: String = self.get_streamer_subscription_keys(acct_ids)?;
+        response::parse_json("GET userprincipals/streamersubscriptionkeys", &res)
+    }
+
+    /// Same as [`get_user_principals`](SyncTDAClient::get_user_principals), deserialized into `UserPrincipals`
+    pub fn get_user_principals_typed(
+        &self,
+        fields: Option<&Vec<&str>>,
+    ) -> Result<UserPrincipals, TDAClientError> {
+        let res: String = self.get_user_principals(fields)?;
+        response::parse_json("GET userprincipals", &res)
+    }
+
+    //// Watchlist ////
+
+    /// Same as [`get_watchlist`](SyncTDAClient::get_watchlist), deserialized into `Watchlist`
+    pub fn get_watchlist_typed(
+        &self,
+        acct_id: i64,
+        watchlist_id: i64,
+    ) -> Result<Watchlist, TDAClientError> {
+        let res: String = self.get_watchlist(acct_id, watchlist_id)?;
+        response::parse_json("GET accounts/{accountId}/watchlists/{watchlistId}", &res)
+    }
+
+    /// Same as [`get_watchlists_for_single_account`](SyncTDAClient::get_watchlists_for_single_account), deserialized into `Vec<Watchlist>`
+    pub fn get_watchlists_for_single_account_typed(
+        &self,
+        acct_id: i64,
+    ) -> Result<Vec<Watchlist>, TDAClientError> {
+        let res: String = self.get_watchlists_for_single_account(acct_id)?;
+        response::parse_json("GET accounts/{accountId}/watchlists", &res)
+    }
+
+    /// Same as [`get_watchlists_for_multiple_accounts`](SyncTDAClient::get_watchlists_for_multiple_accounts), deserialized into `Vec<Watchlist>`
+    pub fn get_watchlists_for_multiple_accounts_typed(
+        &self,
+    ) -> Result<Vec<Watchlist>, TDAClientError> {
+        let res: String = self.get_watchlists_for_multiple_accounts()?;
+        response::parse_json("GET accounts/watchlists", &res)
+    }
+}
@@ -0,0 +1,99 @@
+use crate::clock::ManualClock;
+use crate::config::{
+    AuthConfig, ReauthCallback, DEFAULT_ACCESS_TOKEN_MARGIN, DEFAULT_REFRESH_TOKEN_MARGIN,
+};
+use crate::token::Token;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process;
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+use std::thread;
+
+// Start Time For Tests Using ManualClock
+pub(crate) const NOW: u64 = 1_700_000_000;
+pub(crate) const ACCESS_EXPIRE_TIME: u64 = NOW + 1800;
+pub(crate) const REFRESH_EXPIRE_TIME: u64 = NOW + 7776000;
+
+// Token Issued At NOW
+pub(crate) fn token(access_token: &str, refresh_token: &str) -> Token {
+    serde_json::from_value(serde_json::json!({
+        "access_token": access_token,
+        "refresh_token": refresh_token,
+        "scope": "PlaceTrades AccountAccess MoveMoney",
+        "expires_in": 1800,
+        "refresh_token_expires_in": 7776000,
+        "token_type": "Bearer",
+        "access_token_expire_time": ACCESS_EXPIRE_TIME,
+        "refresh_token_expire_time": REFRESH_EXPIRE_TIME,
+    }))
+    .unwrap()
+}
+
+// Auth Config With Default Margins
+pub(crate) fn auth_config(
+    auth_url: &str,
+    clock: &ManualClock,
+    reauth_callback: Option<ReauthCallback>,
+) -> AuthConfig {
+    AuthConfig {
+        auth_url: auth_url.into(),
+        reauth_callback,
+        access_token_margin: DEFAULT_ACCESS_TOKEN_MARGIN,
+        refresh_token_margin: DEFAULT_REFRESH_TOKEN_MARGIN,
+        clock: Arc::new(clock.clone()),
+    }
+}
+
+// Path In The Temp Directory That's Unique To This Test Process, Removing Anything Left There By An Earlier Run
+pub(crate) fn temp_path(name: &str) -> String {
+    let path: PathBuf =
+        std::env::temp_dir().join(format!("tdameritrade_rust_{}_{}", process::id(), name));
+    std::fs::remove_file(&path).ok();
+
+    path.to_string_lossy().into_owned()
+}
+
+// OAuth Server That Answers Every Request With response_body And Sends Each Request Body Back
+pub(crate) fn oauth_server(response_body: &'static str) -> (String, Receiver<String>) {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let auth_url: String = format!("http://{}/v1/oauth2/token", listener.local_addr().unwrap());
+    let (sender, receiver) = mpsc::channel::<String>();
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            // Read Headers, Then Body
+            let mut content_length: usize = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+
+                if line == "\r\n" {
+                    break;
+                }
+
+                if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+            }
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            sender.send(String::from_utf8(body).unwrap()).ok();
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                response_body.len(),
+                response_body
+            )
+            .unwrap();
+        }
+    });
+
+    (auth_url, receiver)
+}
@@ -1,49 +1,88 @@
+use crate::secret::Secret;
 use crate::TDAClientError;
 use serde::{Deserialize, Serialize};
 use std::fs;
 
-#[derive(Serialize, Deserialize, Debug)]
+// Refresh Tokens Are Valid For 90 Days
+const REFRESH_TOKEN_LIFETIME: u64 = 7776000;
+
+// Token Files Written Before Expire Times Were Stored Hold The Time 75 Days After Issue In refresh_token_expires_in
+const LEGACY_REFRESH_TOKEN_REMAINING: u64 = REFRESH_TOKEN_LIFETIME - 6480000;
+
+/// Token returned by TD Ameritrade's OAuth endpoint. The access and refresh tokens are redacted in `Debug` output
+///
+/// `expires_in` and `refresh_token_expires_in` are the lifetimes in seconds sent by TD Ameritrade. The expire times computed from them are saved with the token
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Token {
-    pub access_token: String,
-    pub refresh_token: String,
+    pub access_token: Secret,
+    pub refresh_token: Secret,
     scope: String,
     expires_in: i64,
     pub refresh_token_expires_in: u64,
     token_type: String,
+    #[serde(default)]
+    access_token_expire_time: u64,
+    #[serde(default)]
+    refresh_token_expire_time: u64,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct NewAccessToken {
-    pub access_token: String,
+    pub access_token: Secret,
     scope: String,
-    expires_in: i64,
+    pub(crate) expires_in: i64,
     token_type: String,
 }
 
 impl Token {
     // Replace Access Token
-    pub fn replace_access_token(&mut self, new_access_token: String) {
+    pub fn replace_access_token(&mut self, new_access_token: Secret) {
         self.access_token = new_access_token;
     }
 
     // Replace Refresh Token
-    pub fn replace_refresh_token(&mut self, new_refresh_token: String) {
+    pub fn replace_refresh_token(&mut self, new_refresh_token: Secret) {
         self.refresh_token = new_refresh_token;
     }
 
+    // Replace Access Token Time
+    pub fn replace_access_token_expire_time(&mut self, new_access_token_expire_time: u64) {
+        self.access_token_expire_time = new_access_token_expire_time
+    }
+
     // Replace Refresh Token Time
     pub fn replace_refresh_token_expire_time(&mut self, new_refresh_token_expire_time: u64) {
-        self.refresh_token_expires_in = new_refresh_token_expire_time
+        self.refresh_token_expire_time = new_refresh_token_expire_time
     }
 
-    // Convert To String
-    pub fn to_string(&self) -> String {
-        serde_json::to_string(&self).unwrap()
+    /// Epoch time in seconds when the access token expires. `0` if unknown
+    pub fn access_token_expire_time(&self) -> u64 {
+        self.access_token_expire_time
+    }
+
+    /// Epoch time in seconds when the refresh token expires
+    pub fn refresh_token_expire_time(&self) -> u64 {
+        if self.refresh_token_expire_time == 0 {
+            self.refresh_token_expires_in + LEGACY_REFRESH_TOKEN_REMAINING
+        } else {
+            self.refresh_token_expire_time
+        }
+    }
+
+    // Set Expire Times From The Lifetimes Sent By TD Ameritrade
+    pub(crate) fn set_expire_times(&mut self, epoch_time: u64) {
+        self.access_token_expire_time = epoch_time + self.expires_in.max(0) as u64;
+        self.refresh_token_expire_time = epoch_time + self.refresh_token_expires_in;
+    }
+
+    // Convert To JSON. Includes The Unredacted Tokens
+    pub(crate) fn to_json(&self) -> Result<String, TDAClientError> {
+        Ok(serde_json::to_string(&self)?)
     }
 }
 
 // Read Token File
-pub fn read_token_file(token_file_path: &String) -> Result<Token, TDAClientError> {
+pub fn read_token_file(token_file_path: &str) -> Result<Token, TDAClientError> {
     // Open File
     let file_contents: String = fs::read_to_string(token_file_path)?;
 
@@ -53,3 +92,47 @@ pub fn read_token_file(token_file_path: &String) -> Result<Token, TDAClientError
     // Return Token
     Ok(token)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_token_file_uses_fallback_refresh_expire_time() {
+        // Token Files Written Before Expire Times Were Stored
+        let token: Token = serde_json::from_str(
+            r#"{
+                "access_token": "access",
+                "refresh_token": "refresh",
+                "scope": "PlaceTrades AccountAccess MoveMoney",
+                "expires_in": 1800,
+                "refresh_token_expires_in": 1700000000,
+                "token_type": "Bearer"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(token.access_token_expire_time(), 0);
+        assert_eq!(token.refresh_token_expire_time(), 1700000000 + 1296000);
+    }
+
+    #[test]
+    fn expire_times_are_set_from_lifetimes() {
+        let mut token: Token = serde_json::from_str(
+            r#"{
+                "access_token": "access",
+                "refresh_token": "refresh",
+                "scope": "",
+                "expires_in": 1800,
+                "refresh_token_expires_in": 7776000,
+                "token_type": "Bearer"
+            }"#,
+        )
+        .unwrap();
+
+        token.set_expire_times(1000);
+
+        assert_eq!(token.access_token_expire_time(), 2800);
+        assert_eq!(token.refresh_token_expire_time(), 7777000);
+    }
+}
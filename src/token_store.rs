@@ -0,0 +1,566 @@
+use crate::secret::Secret;
+use crate::token::{read_token_file, Token};
+use crate::TDAClientError;
+use argon2::Argon2;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fmt::{self, Debug, Formatter};
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::process;
+use std::sync::Mutex;
+use zeroize::Zeroizing;
+
+// Version Of The Encrypted Token File Format
+const ENCRYPTED_TOKEN_FILE_VERSION: u8 = 1;
+
+/// Where the client loads its token from and saves refreshed tokens to
+///
+/// Implement this trait to keep tokens somewhere else, such as a database or secret manager
+pub trait TokenStore: Send + Sync {
+    /// Load the current token
+    fn load(&self) -> Result<Token, TDAClientError>;
+
+    /// Save a refreshed token
+    fn save(&self, token: &Token) -> Result<(), TDAClientError>;
+
+    /// Lock the store across processes while a token is refreshed. The lock is released when the returned guard is dropped
+    ///
+    /// The client reloads the token after locking, so a token refreshed by another process is used instead of refreshing again. Does nothing by default
+    fn lock(&self) -> Result<TokenStoreLock, TDAClientError> {
+        Ok(TokenStoreLock::none())
+    }
+}
+
+/// Guard returned by `TokenStore::lock`. Releases the lock when dropped
+pub struct TokenStoreLock {
+    _guard: Option<Box<dyn Send>>,
+}
+
+impl TokenStoreLock {
+    /// Hold `guard` until the lock is dropped, e.g. a locked file
+    pub fn new(guard: impl Send + 'static) -> TokenStoreLock {
+        TokenStoreLock {
+            _guard: Some(Box::new(guard)),
+        }
+    }
+
+    /// Lock that doesn't hold anything
+    pub fn none() -> TokenStoreLock {
+        TokenStoreLock { _guard: None }
+    }
+}
+
+impl Debug for TokenStoreLock {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "TokenStoreLock")
+    }
+}
+
+impl Debug for dyn TokenStore {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "TokenStore")
+    }
+}
+
+/// Stores the token as JSON in a file. This is the format created by `init::create_token_file`
+///
+/// Saves replace the file atomically, and refreshes are locked with `<token_path>.lock` so several processes can share one token file
+#[derive(Debug, Clone)]
+pub struct FileTokenStore {
+    token_path: String,
+}
+
+impl FileTokenStore {
+    pub fn new(token_path: String) -> FileTokenStore {
+        FileTokenStore { token_path }
+    }
+}
+
+impl TokenStore for FileTokenStore {
+    fn load(&self) -> Result<Token, TDAClientError> {
+        read_token_file(&self.token_path)
+    }
+
+    fn save(&self, token: &Token) -> Result<(), TDAClientError> {
+        write_atomic(&self.token_path, token.to_json()?.as_bytes())
+    }
+
+    fn lock(&self) -> Result<TokenStoreLock, TDAClientError> {
+        lock_file(&self.token_path)
+    }
+}
+
+/// Keeps the token in memory. Refreshed tokens are lost when the process exits
+#[derive(Debug)]
+pub struct MemoryTokenStore {
+    token: Mutex<Token>,
+}
+
+impl MemoryTokenStore {
+    pub fn new(token: Token) -> MemoryTokenStore {
+        MemoryTokenStore {
+            token: Mutex::new(token),
+        }
+    }
+}
+
+impl TokenStore for MemoryTokenStore {
+    fn load(&self) -> Result<Token, TDAClientError> {
+        Ok(self.token.lock().unwrap().clone())
+    }
+
+    fn save(&self, token: &Token) -> Result<(), TDAClientError> {
+        *self.token.lock().unwrap() = token.clone();
+        Ok(())
+    }
+}
+
+/// Reads the token as JSON from an environment variable the first time it's loaded
+///
+/// Refreshed tokens are kept in memory and never written back to the environment, so the refresh token must be rotated outside of the process before it expires
+#[derive(Debug)]
+pub struct EnvTokenStore {
+    var_name: String,
+    token: Mutex<Option<Token>>,
+}
+
+impl EnvTokenStore {
+    pub fn new(var_name: String) -> EnvTokenStore {
+        EnvTokenStore {
+            var_name,
+            token: Mutex::new(None),
+        }
+    }
+}
+
+impl TokenStore for EnvTokenStore {
+    fn load(&self) -> Result<Token, TDAClientError> {
+        let mut token = self.token.lock().unwrap();
+
+        // Read Environment Variable Once
+        if token.is_none() {
+            let token_json: String = env::var(&self.var_name).map_err(|err| {
+                TDAClientError::TokenStoreError(format!("{}: {}", self.var_name, err))
+            })?;
+
+            // Convert To Token
+            *token = Some(serde_json::from_str(&token_json)?);
+        }
+
+        Ok(token.clone().unwrap())
+    }
+
+    fn save(&self, token: &Token) -> Result<(), TDAClientError> {
+        *self.token.lock().unwrap() = Some(token.clone());
+        Ok(())
+    }
+}
+
+/// Stores the token in a file encrypted with XChaCha20-Poly1305, using a key derived with Argon2id from a passphrase or key file
+///
+/// To encrypt an existing token file, load it with `FileTokenStore` and save it with `EncryptedFileTokenStore`
+pub struct EncryptedFileTokenStore {
+    token_path: String,
+    key_material: Zeroizing<Vec<u8>>,
+}
+
+// Encrypted Token File Contents
+#[derive(Serialize, Deserialize)]
+struct EncryptedTokenFile {
+    version: u8,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl EncryptedFileTokenStore {
+    /// Derive the encryption key from a passphrase
+    pub fn with_passphrase(token_path: String, passphrase: Secret) -> EncryptedFileTokenStore {
+        EncryptedFileTokenStore {
+            token_path,
+            key_material: Zeroizing::new(passphrase.expose_secret().as_bytes().to_vec()),
+        }
+    }
+
+    /// Derive the encryption key from the contents of a key file
+    pub fn with_key_file(
+        token_path: String,
+        key_file_path: &str,
+    ) -> Result<EncryptedFileTokenStore, TDAClientError> {
+        // Read Key File
+        let key_material: Zeroizing<Vec<u8>> = Zeroizing::new(fs::read(key_file_path)?);
+
+        if key_material.is_empty() {
+            return Err(TDAClientError::TokenStoreError(format!(
+                "Key File Is Empty: {}",
+                key_file_path
+            )));
+        }
+
+        Ok(EncryptedFileTokenStore {
+            token_path,
+            key_material,
+        })
+    }
+
+    // Create Cipher From Key Material And Salt
+    fn cipher(&self, salt: &[u8]) -> Result<XChaCha20Poly1305, TDAClientError> {
+        let mut key: Zeroizing<[u8; 32]> = Zeroizing::new([0; 32]);
+
+        Argon2::default()
+            .hash_password_into(&self.key_material, salt, key.as_mut())
+            .map_err(|err| TDAClientError::TokenStoreError(format!("Key Derivation: {}", err)))?;
+
+        Ok(XChaCha20Poly1305::new(key.as_ref().into()))
+    }
+}
+
+impl Debug for EncryptedFileTokenStore {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("EncryptedFileTokenStore")
+            .field("token_path", &self.token_path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TokenStore for EncryptedFileTokenStore {
+    fn load(&self) -> Result<Token, TDAClientError> {
+        // Read Encrypted Token File
+        let file_contents: String = fs::read_to_string(&self.token_path)?;
+        let encrypted: EncryptedTokenFile = serde_json::from_str(&file_contents)?;
+
+        if encrypted.version != ENCRYPTED_TOKEN_FILE_VERSION {
+            return Err(TDAClientError::TokenStoreError(format!(
+                "Unsupported Encrypted Token File Version: {}",
+                encrypted.version
+            )));
+        }
+
+        // Decode Fields
+        let decode = |value: &str| {
+            BASE64
+                .decode(value)
+                .map_err(|err| TDAClientError::TokenStoreError(format!("Invalid Base64: {}", err)))
+        };
+        let salt: Vec<u8> = decode(&encrypted.salt)?;
+        let nonce: Vec<u8> = decode(&encrypted.nonce)?;
+        let ciphertext: Vec<u8> = decode(&encrypted.ciphertext)?;
+
+        if nonce.len() != 24 {
+            return Err(TDAClientError::TokenStoreError("Invalid Nonce".into()));
+        }
+
+        // Decrypt Token
+        let plaintext: Zeroizing<Vec<u8>> = Zeroizing::new(
+            self.cipher(&salt)?
+                .decrypt(XNonce::from_slice(&nonce), ciphertext.as_ref())
+                .map_err(|_| {
+                    TDAClientError::TokenStoreError(
+                        "Unable To Decrypt Token File. Wrong Passphrase Or Key File?".into(),
+                    )
+                })?,
+        );
+
+        Ok(serde_json::from_slice(&plaintext)?)
+    }
+
+    fn save(&self, token: &Token) -> Result<(), TDAClientError> {
+        // Generate Salt And Nonce
+        let mut salt: [u8; 16] = [0; 16];
+        OsRng.fill_bytes(&mut salt);
+        let nonce: XNonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+        // Encrypt Token
+        let plaintext: Zeroizing<String> = Zeroizing::new(token.to_json()?);
+        let ciphertext: Vec<u8> = self
+            .cipher(&salt)?
+            .encrypt(&nonce, plaintext.as_bytes())
+            .map_err(|_| TDAClientError::TokenStoreError("Unable To Encrypt Token".into()))?;
+
+        // Write Encrypted Token File
+        let encrypted: EncryptedTokenFile = EncryptedTokenFile {
+            version: ENCRYPTED_TOKEN_FILE_VERSION,
+            salt: BASE64.encode(salt),
+            nonce: BASE64.encode(nonce),
+            ciphertext: BASE64.encode(ciphertext),
+        };
+
+        write_atomic(
+            &self.token_path,
+            serde_json::to_string(&encrypted)?.as_bytes(),
+        )
+    }
+
+    fn lock(&self) -> Result<TokenStoreLock, TDAClientError> {
+        lock_file(&self.token_path)
+    }
+}
+
+// Write To A Temporary File And Rename It, So Readers Never See A Partially Written Token
+fn write_atomic(path: &str, contents: &[u8]) -> Result<(), TDAClientError> {
+    let temp_path: String = format!("{}.{}.tmp", path, process::id());
+
+    let mut options: OpenOptions = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    // Only The Owner Can Read The Token
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file: File = options.open(&temp_path)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+
+    fs::rename(&temp_path, path).inspect_err(|_| {
+        fs::remove_file(&temp_path).ok();
+    })?;
+
+    Ok(())
+}
+
+// Take An Exclusive Advisory Lock On <path>.lock, Waiting For Other Processes To Release It
+fn lock_file(path: &str) -> Result<TokenStoreLock, TDAClientError> {
+    let lock_path: String = format!("{}.lock", path);
+
+    let file: File = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(&lock_path)?;
+
+    file.lock()?;
+
+    Ok(TokenStoreLock::new(file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::temp_path;
+
+    fn token(access_token: &str) -> Token {
+        serde_json::from_value(serde_json::json!({
+            "access_token": access_token,
+            "refresh_token": "refresh",
+            "scope": "PlaceTrades AccountAccess MoveMoney",
+            "expires_in": 1800,
+            "refresh_token_expires_in": 7776000,
+            "token_type": "Bearer",
+            "access_token_expire_time": 1_700_001_800,
+            "refresh_token_expire_time": 1_707_776_000,
+        }))
+        .unwrap()
+    }
+
+    // Save Then Load A Token, Returning The Loaded Token As JSON
+    fn round_trip(token_store: &dyn TokenStore, access_token: &str) -> String {
+        token_store.save(&token(access_token)).unwrap();
+        token_store.load().unwrap().to_json().unwrap()
+    }
+
+    #[test]
+    fn file_token_store_round_trips() {
+        let token_path: String = temp_path("file_token_store.json");
+        let token_store = FileTokenStore::new(token_path.clone());
+
+        assert!(token_store.load().is_err());
+        assert_eq!(
+            round_trip(&token_store, "first"),
+            token("first").to_json().unwrap()
+        );
+        assert_eq!(
+            round_trip(&token_store, "second"),
+            token("second").to_json().unwrap()
+        );
+
+        // Readable By init's Token File Reader
+        assert_eq!(
+            read_token_file(&token_path).unwrap().access_token,
+            Secret::new("second".into())
+        );
+
+        fs::remove_file(token_path).unwrap();
+    }
+
+    #[test]
+    fn memory_token_store_round_trips() {
+        let token_store = MemoryTokenStore::new(token("first"));
+
+        assert_eq!(
+            token_store.load().unwrap().to_json().unwrap(),
+            token("first").to_json().unwrap()
+        );
+        assert_eq!(
+            round_trip(&token_store, "second"),
+            token("second").to_json().unwrap()
+        );
+    }
+
+    #[test]
+    fn env_token_store_keeps_saved_token_out_of_environment() {
+        let var_name: &str = "TDAMERITRADE_RUST_TEST_UNSET_TOKEN";
+        let token_store = EnvTokenStore::new(var_name.into());
+
+        // Variable Isn't Set
+        assert!(matches!(
+            token_store.load(),
+            Err(TDAClientError::TokenStoreError(message)) if message.starts_with(var_name)
+        ));
+
+        // Saved Token Is Loaded Without Touching The Environment
+        assert_eq!(
+            round_trip(&token_store, "refreshed"),
+            token("refreshed").to_json().unwrap()
+        );
+        assert!(env::var_os(var_name).is_none());
+    }
+
+    #[test]
+    fn encrypted_file_token_store_round_trips() {
+        let token_path: String = temp_path("encrypted_token_store.enc");
+        let token_store = EncryptedFileTokenStore::with_passphrase(
+            token_path.clone(),
+            Secret::new("passphrase".into()),
+        );
+
+        assert_eq!(
+            round_trip(&token_store, "access"),
+            token("access").to_json().unwrap()
+        );
+
+        // Token Isn't Stored In Plain Text
+        let file_contents: String = fs::read_to_string(&token_path).unwrap();
+        assert!(!file_contents.contains("access"));
+        assert!(!file_contents.contains("refresh"));
+
+        fs::remove_file(token_path).unwrap();
+    }
+
+    #[test]
+    fn encrypted_file_token_store_with_key_file_round_trips() {
+        let token_path: String = temp_path("key_file_token_store.enc");
+        let key_file_path: String = temp_path("key_file_token_store.key");
+        fs::write(&key_file_path, [7; 32]).unwrap();
+
+        let token_store =
+            EncryptedFileTokenStore::with_key_file(token_path.clone(), &key_file_path).unwrap();
+
+        assert_eq!(
+            round_trip(&token_store, "access"),
+            token("access").to_json().unwrap()
+        );
+
+        // Empty Key Files Are Rejected
+        fs::write(&key_file_path, []).unwrap();
+        assert!(matches!(
+            EncryptedFileTokenStore::with_key_file(token_path.clone(), &key_file_path),
+            Err(TDAClientError::TokenStoreError(_))
+        ));
+
+        fs::remove_file(token_path).unwrap();
+        fs::remove_file(key_file_path).unwrap();
+    }
+
+    #[test]
+    fn encrypted_file_token_store_rejects_wrong_passphrase() {
+        let token_path: String = temp_path("wrong_passphrase_token_store.enc");
+
+        EncryptedFileTokenStore::with_passphrase(token_path.clone(), Secret::new("right".into()))
+            .save(&token("access"))
+            .unwrap();
+
+        let token_store = EncryptedFileTokenStore::with_passphrase(
+            token_path.clone(),
+            Secret::new("wrong".into()),
+        );
+
+        assert!(matches!(
+            token_store.load(),
+            Err(TDAClientError::TokenStoreError(message)) if message.contains("Wrong Passphrase")
+        ));
+
+        fs::remove_file(token_path).unwrap();
+    }
+
+    #[test]
+    fn debug_output_is_redacted() {
+        let token_store = EncryptedFileTokenStore::with_passphrase(
+            "token.enc".into(),
+            Secret::new("passphrase".into()),
+        );
+
+        assert!(!format!("{:?}", token_store).contains("passphrase"));
+        assert!(!format!("{:?}", MemoryTokenStore::new(token("access"))).contains("access\""));
+    }
+
+    #[test]
+    fn atomic_write_replaces_file_without_leaving_temp_file() {
+        let path: String = temp_path("atomic_write.json");
+        fs::write(&path, "old").unwrap();
+
+        write_atomic(&path, b"new").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        assert!(fs::metadata(format!("{}.{}.tmp", path, process::id())).is_err());
+
+        // Only The Owner Can Read The Token
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            assert_eq!(
+                fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+                0o600
+            );
+        }
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn failed_atomic_write_keeps_original() {
+        // Renaming Over A Directory Fails
+        let path: String = temp_path("atomic_write_dir");
+        fs::create_dir_all(&path).unwrap();
+
+        assert!(write_atomic(&path, b"new").is_err());
+        assert!(fs::metadata(&path).unwrap().is_dir());
+        assert!(fs::metadata(format!("{}.{}.tmp", path, process::id())).is_err());
+
+        fs::remove_dir(path).unwrap();
+    }
+
+    #[test]
+    fn lock_waits_for_other_holder() {
+        let token_path: String = temp_path("locked_token.json");
+        let lock_path: String = format!("{}.lock", token_path);
+        let token_store = FileTokenStore::new(token_path.clone());
+
+        let lock: TokenStoreLock = token_store.lock().unwrap();
+        let (sender, receiver) = std::sync::mpsc::channel::<()>();
+
+        // Separate File Handle, Like Another Process
+        let waiter = std::thread::spawn(move || {
+            let _lock: TokenStoreLock = FileTokenStore::new(token_path).lock().unwrap();
+            sender.send(()).unwrap();
+        });
+
+        assert!(receiver
+            .recv_timeout(std::time::Duration::from_millis(200))
+            .is_err());
+
+        drop(lock);
+
+        assert!(receiver
+            .recv_timeout(std::time::Duration::from_secs(5))
+            .is_ok());
+        waiter.join().unwrap();
+
+        fs::remove_file(lock_path).unwrap();
+    }
+}
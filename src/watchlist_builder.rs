@@ -0,0 +1,351 @@
+use crate::order_enums::AssetType;
+use crate::TDAClientError;
+use serde::{Deserialize, Serialize};
+
+/// Builder for the watchlist body of `create_watchlist` and `replace_watchlist`
+///
+/// Parameters
+/// - name: Watchlist name. Accepts `&str`
+/// - watchlist_id: Watchlist number, only needed by `replace_watchlist`. Accepts `i64`
+/// - watchlist_items: Watchlist items. Accepts `WatchlistItem`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchlistBuilder {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    watchlist_id: Option<String>,
+    watchlist_items: Vec<WatchlistItem>,
+}
+
+/// Builder for the watchlist body of `update_watchlist`
+///
+/// Parameters
+/// - name: New watchlist name. Accepts `&str`
+/// - watchlist_id: Watchlist number. Accepts `i64`
+/// - watchlist_items: Items to append, update, or delete
+///
+/// Items without a sequence id are appended to the end of the watchlist, items with a sequence id and an instrument replace the item with that sequence id, and items with only a sequence id are deleted
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchlistPatchBuilder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    watchlist_id: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    watchlist_items: Vec<WatchlistPatchItem>,
+}
+
+/// Watchlist item
+///
+/// Parameters
+/// - symbol: Symbol to watch. Accepts `&str`, `String`, or `OptionSymbol`
+/// - asset_type: Type of asset. Accepts `AssetType`
+/// - quantity: Number of shares held. Defaults to 0. Accepts `f64`
+/// - average_price: Average price paid. Defaults to 0. Accepts `f64`
+/// - commission: Commission paid. Defaults to 0. Accepts `f64`
+/// - purchased_date: Purchase date as `yyyy-MM-dd`. Accepts `&str`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchlistItem {
+    quantity: f64,
+    average_price: f64,
+    commission: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purchased_date: Option<String>,
+    instrument: WatchlistInstrument,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WatchlistInstrument {
+    symbol: String,
+    asset_type: AssetType,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WatchlistPatchItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sequence_id: Option<i64>,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    watchlist_item: Option<WatchlistItem>,
+}
+
+impl WatchlistBuilder {
+    pub fn new(name: &str) -> WatchlistBuilder {
+        WatchlistBuilder {
+            name: name.to_string(),
+            watchlist_id: None,
+            watchlist_items: Vec::new(),
+        }
+    }
+
+    // Name
+    pub fn set_name(mut self, name: &str) -> WatchlistBuilder {
+        self.name = name.to_string();
+        self
+    }
+
+    // Watchlist Id
+    pub fn set_watchlist_id(mut self, watchlist_id: i64) -> WatchlistBuilder {
+        self.watchlist_id = Some(watchlist_id.to_string());
+        self
+    }
+
+    pub fn clear_watchlist_id(mut self) -> WatchlistBuilder {
+        self.watchlist_id = None;
+        self
+    }
+
+    // Watchlist Items
+    pub fn add_watchlist_item(mut self, watchlist_item: WatchlistItem) -> WatchlistBuilder {
+        self.watchlist_items.push(watchlist_item);
+        self
+    }
+
+    /// Add watchlist item with no position details
+    ///
+    /// Parameters
+    /// - symbol: Symbol to watch. Accepts `&str`, `String`, or `OptionSymbol`
+    /// - asset_type: Type of asset. Accepts `AssetType`
+    pub fn add_symbol(self, symbol: impl ToString, asset_type: AssetType) -> WatchlistBuilder {
+        self.add_watchlist_item(WatchlistItem::new(symbol, asset_type))
+    }
+
+    pub fn clear_watchlist_items(mut self) -> WatchlistBuilder {
+        self.watchlist_items = Vec::new();
+        self
+    }
+
+    // Build Watchlist JSON
+    pub fn build(self) -> Result<String, TDAClientError> {
+        Ok(serde_json::to_string_pretty(&self)?)
+    }
+}
+
+impl WatchlistPatchBuilder {
+    pub fn new() -> WatchlistPatchBuilder {
+        WatchlistPatchBuilder::default()
+    }
+
+    // Name
+    pub fn set_name(mut self, name: &str) -> WatchlistPatchBuilder {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn clear_name(mut self) -> WatchlistPatchBuilder {
+        self.name = None;
+        self
+    }
+
+    // Watchlist Id
+    pub fn set_watchlist_id(mut self, watchlist_id: i64) -> WatchlistPatchBuilder {
+        self.watchlist_id = Some(watchlist_id.to_string());
+        self
+    }
+
+    pub fn clear_watchlist_id(mut self) -> WatchlistPatchBuilder {
+        self.watchlist_id = None;
+        self
+    }
+
+    /// Append item to the end of the watchlist
+    pub fn append_watchlist_item(mut self, watchlist_item: WatchlistItem) -> WatchlistPatchBuilder {
+        self.watchlist_items.push(WatchlistPatchItem {
+            sequence_id: None,
+            watchlist_item: Some(watchlist_item),
+        });
+        self
+    }
+
+    /// Replace the item with `sequence_id`
+    ///
+    /// Parameters
+    /// - sequence_id: Sequence id of the item, from `WatchlistData::sequence_id`
+    /// - watchlist_item: New item
+    pub fn update_watchlist_item(
+        mut self,
+        sequence_id: i64,
+        watchlist_item: WatchlistItem,
+    ) -> WatchlistPatchBuilder {
+        self.watchlist_items.push(WatchlistPatchItem {
+            sequence_id: Some(sequence_id),
+            watchlist_item: Some(watchlist_item),
+        });
+        self
+    }
+
+    /// Delete the item with `sequence_id`
+    ///
+    /// Parameters
+    /// - sequence_id: Sequence id of the item, from `WatchlistData::sequence_id`
+    pub fn delete_watchlist_item(mut self, sequence_id: i64) -> WatchlistPatchBuilder {
+        self.watchlist_items.push(WatchlistPatchItem {
+            sequence_id: Some(sequence_id),
+            watchlist_item: None,
+        });
+        self
+    }
+
+    pub fn clear_watchlist_items(mut self) -> WatchlistPatchBuilder {
+        self.watchlist_items = Vec::new();
+        self
+    }
+
+    // Build Watchlist Patch JSON
+    pub fn build(self) -> Result<String, TDAClientError> {
+        Ok(serde_json::to_string_pretty(&self)?)
+    }
+}
+
+impl WatchlistItem {
+    pub fn new(symbol: impl ToString, asset_type: AssetType) -> WatchlistItem {
+        WatchlistItem {
+            quantity: 0.0,
+            average_price: 0.0,
+            commission: 0.0,
+            purchased_date: None,
+            instrument: WatchlistInstrument {
+                symbol: symbol.to_string(),
+                asset_type,
+            },
+        }
+    }
+
+    // Quantity
+    pub fn set_quantity(mut self, quantity: f64) -> WatchlistItem {
+        self.quantity = quantity;
+        self
+    }
+
+    // Average Price
+    pub fn set_average_price(mut self, average_price: f64) -> WatchlistItem {
+        self.average_price = average_price;
+        self
+    }
+
+    // Commission
+    pub fn set_commission(mut self, commission: f64) -> WatchlistItem {
+        self.commission = commission;
+        self
+    }
+
+    // Purchased Date
+    pub fn set_purchased_date(mut self, purchased_date: &str) -> WatchlistItem {
+        self.purchased_date = Some(purchased_date.to_string());
+        self
+    }
+
+    pub fn clear_purchased_date(mut self) -> WatchlistItem {
+        self.purchased_date = None;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn watchlist_json(body: Result<String, TDAClientError>) -> Value {
+        serde_json::from_str(&body.unwrap()).unwrap()
+    }
+
+    #[test]
+    fn builds_create_body() {
+        let body = WatchlistBuilder::new("Tech")
+            .add_symbol("AAPL", AssetType::Equity)
+            .add_watchlist_item(
+                WatchlistItem::new("MSFT", AssetType::Equity)
+                    .set_quantity(10.0)
+                    .set_average_price(300.5)
+                    .set_commission(1.0)
+                    .set_purchased_date("2022-01-03"),
+            )
+            .build();
+
+        assert_eq!(
+            watchlist_json(body),
+            json!({
+                "name": "Tech",
+                "watchlistItems": [
+                    {
+                        "quantity": 0.0,
+                        "averagePrice": 0.0,
+                        "commission": 0.0,
+                        "instrument": { "symbol": "AAPL", "assetType": "EQUITY" }
+                    },
+                    {
+                        "quantity": 10.0,
+                        "averagePrice": 300.5,
+                        "commission": 1.0,
+                        "purchasedDate": "2022-01-03",
+                        "instrument": { "symbol": "MSFT", "assetType": "EQUITY" }
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn replace_body_carries_watchlist_id() {
+        let body = WatchlistBuilder::new("Tech")
+            .set_watchlist_id(123)
+            .add_symbol("AAPL", AssetType::Equity)
+            .build();
+
+        assert_eq!(watchlist_json(body)["watchlistId"], "123");
+    }
+
+    #[test]
+    fn builds_patch_body() {
+        let body = WatchlistPatchBuilder::new()
+            .set_name("Renamed")
+            .set_watchlist_id(123)
+            .update_watchlist_item(
+                1,
+                WatchlistItem::new("AAPL", AssetType::Equity).set_quantity(5.0),
+            )
+            .append_watchlist_item(WatchlistItem::new("SPY_011924C400", AssetType::Option))
+            .delete_watchlist_item(2)
+            .build();
+
+        assert_eq!(
+            watchlist_json(body),
+            json!({
+                "name": "Renamed",
+                "watchlistId": "123",
+                "watchlistItems": [
+                    {
+                        "sequenceId": 1,
+                        "quantity": 5.0,
+                        "averagePrice": 0.0,
+                        "commission": 0.0,
+                        "instrument": { "symbol": "AAPL", "assetType": "EQUITY" }
+                    },
+                    {
+                        "quantity": 0.0,
+                        "averagePrice": 0.0,
+                        "commission": 0.0,
+                        "instrument": { "symbol": "SPY_011924C400", "assetType": "OPTION" }
+                    },
+                    { "sequenceId": 2 }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn delete_item_is_only_a_sequence_id() {
+        let body = WatchlistPatchBuilder::new()
+            .delete_watchlist_item(7)
+            .build();
+
+        assert_eq!(
+            watchlist_json(body),
+            json!({ "watchlistItems": [{ "sequenceId": 7 }] })
+        );
+    }
+}
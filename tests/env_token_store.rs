@@ -0,0 +1,34 @@
+// Only Test In This Binary, So No Other Thread Reads The Environment While It's Set
+use std::env;
+use tdameritrade_rust::{EnvTokenStore, TokenStore};
+
+const VAR_NAME: &str = "TDAMERITRADE_RUST_TEST_TOKEN";
+
+#[test]
+fn env_token_store_reads_environment_once() {
+    env::set_var(
+        VAR_NAME,
+        r#"{
+            "access_token": "access",
+            "refresh_token": "refresh",
+            "scope": "PlaceTrades AccountAccess MoveMoney",
+            "expires_in": 1800,
+            "refresh_token_expires_in": 7776000,
+            "token_type": "Bearer"
+        }"#,
+    );
+
+    let token_store = EnvTokenStore::new(VAR_NAME.into());
+    let mut token = token_store.load().unwrap();
+    assert_eq!(token.access_token.expose_secret(), "access");
+
+    // Later Loads Return The Saved Token And Ignore The Environment
+    token.replace_access_token("refreshed".to_string().into());
+    token_store.save(&token).unwrap();
+    env::remove_var(VAR_NAME);
+
+    assert_eq!(
+        token_store.load().unwrap().access_token.expose_secret(),
+        "refreshed"
+    );
+}